@@ -43,12 +43,20 @@ impl Styler for UiStyler {
         &self,
         styled: T,
     ) -> T {
-        styled.padding(UiRect::all(Val::Px(10.)))
+        styled.padding(UiValRect::all(UiVal::Em(0.4)))
     }
 }
 
 fn setup(mut commands: Commands, assets: ResMut<AssetServer>) {
     let font: Handle<Font> = assets.load("libre-baskerville/LibreBaskerville-Regular.ttf");
+    commands.insert_resource(
+        FontFamilies::default().with_family(
+            DEFAULT_FONT_FAMILY,
+            FontFamily::new(font.clone())
+                .with_bold(assets.load("libre-baskerville/LibreBaskerville-Bold.ttf"))
+                .with_italic(assets.load("libre-baskerville/LibreBaskerville-Italic.ttf")),
+        ),
+    );
     let image: Handle<Image> = assets.load("test-image.png");
     commands.spawn(Camera2dBundle::default());
 
@@ -65,13 +73,14 @@ fn setup(mut commands: Commands, assets: ResMut<AssetServer>) {
             p.node()
                 .style(UiStyler::Heading(font.clone()))
                 .with_children(|mut p| {
-                    p.text("HEADING!");
+                    p.text("HEADING!").bold();
                 });
             p.text("A IS NOT PRESSED").id(15);
             p.text("I don't change...").id(16);
             p.image(image)
                 .width(Val::Px(150.))
                 .height(Val::Px(150.))
+                .tooltip("An image")
                 .id(16);
         });
 }
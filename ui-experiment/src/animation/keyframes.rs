@@ -0,0 +1,573 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::prelude::*;
+
+use crate::{
+    lerp_color, lerp_val, Easing, InternalUiSpawner, LengthField, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiComponent, UiStyleSet,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum AnimatedProperty {
+    BackgroundColor,
+    TextColor,
+    Length(LengthField),
+    FlipX,
+    FlipY,
+    Visibility,
+}
+
+impl AnimatedProperty {
+    pub const LEFT: AnimatedProperty = AnimatedProperty::Length(LengthField::Left);
+    pub const TOP: AnimatedProperty = AnimatedProperty::Length(LengthField::Top);
+    pub const WIDTH: AnimatedProperty = AnimatedProperty::Length(LengthField::Width);
+    pub const HEIGHT: AnimatedProperty = AnimatedProperty::Length(LengthField::Height);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnimationValue {
+    Color(Color),
+    Val(Val),
+    Bool(bool),
+    Visibility(Visibility),
+}
+
+impl AnimationValue {
+    fn lerp(self, to: AnimationValue, t: f32) -> AnimationValue {
+        match (self, to) {
+            (AnimationValue::Color(from), AnimationValue::Color(to)) => {
+                AnimationValue::Color(lerp_color(from, to, t))
+            }
+            (AnimationValue::Val(from), AnimationValue::Val(to)) => match lerp_val(from, to, t) {
+                Some(val) => AnimationValue::Val(val),
+                None if t < 1. => AnimationValue::Val(from),
+                None => AnimationValue::Val(to),
+            },
+            _ if t < 1. => self,
+            _ => to,
+        }
+    }
+}
+
+impl From<Color> for AnimationValue {
+    fn from(value: Color) -> Self {
+        AnimationValue::Color(value)
+    }
+}
+
+impl From<Val> for AnimationValue {
+    fn from(value: Val) -> Self {
+        AnimationValue::Val(value)
+    }
+}
+
+impl From<bool> for AnimationValue {
+    fn from(value: bool) -> Self {
+        AnimationValue::Bool(value)
+    }
+}
+
+impl From<Visibility> for AnimationValue {
+    fn from(value: Visibility) -> Self {
+        AnimationValue::Visibility(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Keyframe {
+    pub time: f32,
+    pub value: AnimationValue,
+    pub easing: Easing,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnimationTrack {
+    pub property: AnimatedProperty,
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl AnimationTrack {
+    fn sample(&self, time: f32) -> Option<AnimationValue> {
+        let first = self.keyframes.first()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+        let Some(next) = self
+            .keyframes
+            .iter()
+            .position(|keyframe| keyframe.time > time)
+        else {
+            return self.keyframes.last().map(|keyframe| keyframe.value);
+        };
+        let (from, to) = (self.keyframes[next - 1], self.keyframes[next]);
+        let t = (time - from.time) / (to.time - from.time);
+        Some(from.value.lerp(to.value, to.easing.apply(t)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationRepeat {
+    #[default]
+    Once,
+    Times(u32),
+    Forever,
+}
+
+#[derive(Clone, Debug)]
+pub struct UiAnimation {
+    pub tracks: Vec<AnimationTrack>,
+    pub repeat: AnimationRepeat,
+    pub ping_pong: bool,
+    pub delay: f32,
+    pub speed: f32,
+}
+
+impl Default for UiAnimation {
+    fn default() -> Self {
+        Self {
+            tracks: Vec::new(),
+            repeat: AnimationRepeat::Once,
+            ping_pong: false,
+            delay: 0.,
+            speed: 1.,
+        }
+    }
+}
+
+impl UiAnimation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keyframe(
+        self,
+        time: f32,
+        property: AnimatedProperty,
+        value: impl Into<AnimationValue>,
+    ) -> Self {
+        self.keyframe_eased(time, property, value, Easing::Linear)
+    }
+
+    pub fn keyframe_eased(
+        mut self,
+        time: f32,
+        property: AnimatedProperty,
+        value: impl Into<AnimationValue>,
+        easing: Easing,
+    ) -> Self {
+        let keyframe = Keyframe {
+            time,
+            value: value.into(),
+            easing,
+        };
+        let track = match self
+            .tracks
+            .iter_mut()
+            .position(|track| track.property == property)
+        {
+            Some(index) => &mut self.tracks[index],
+            None => {
+                self.tracks.push(AnimationTrack {
+                    property,
+                    keyframes: Vec::new(),
+                });
+                self.tracks.last_mut().unwrap()
+            }
+        };
+        let index = track
+            .keyframes
+            .partition_point(|existing| existing.time <= time);
+        track.keyframes.insert(index, keyframe);
+        self
+    }
+
+    pub fn repeat(mut self, repeat: AnimationRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
+    }
+
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.tracks
+            .iter()
+            .filter_map(|track| track.keyframes.last())
+            .map(|keyframe| keyframe.time)
+            .fold(0., f32::max)
+    }
+
+    fn iterations(&self) -> Option<u32> {
+        match self.repeat {
+            AnimationRepeat::Once => Some(1),
+            AnimationRepeat::Times(times) => Some(times.max(1)),
+            AnimationRepeat::Forever => None,
+        }
+    }
+
+    /// Splits `local` (seconds since the delay elapsed, already scaled by
+    /// `speed`) into the current cycle, the time to sample within it and
+    /// whether the last cycle has completed.
+    fn playhead(&self, local: f32) -> (u32, f32, bool) {
+        let duration = self.duration();
+        let total = self.iterations();
+        let cycle = if duration > 0. {
+            (local / duration) as u32
+        } else {
+            total.unwrap_or(1)
+        };
+        let (cycle, phase, finished) = match total {
+            Some(total) if cycle >= total => (total - 1, duration, true),
+            _ => (cycle, local - cycle as f32 * duration, false),
+        };
+        let time = if self.ping_pong && cycle % 2 == 1 {
+            duration - phase
+        } else {
+            phase
+        };
+        (cycle, time, finished)
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct UiAnimationPlayer {
+    animation: UiAnimation,
+    elapsed: f32,
+    completed: u32,
+    paused: bool,
+    finished: bool,
+}
+
+impl UiAnimationPlayer {
+    pub fn new(animation: UiAnimation) -> Self {
+        Self {
+            animation,
+            elapsed: 0.,
+            completed: 0,
+            paused: false,
+            finished: false,
+        }
+    }
+
+    pub fn animation(&self) -> &UiAnimation {
+        &self.animation
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed = 0.;
+        self.completed = 0;
+        self.finished = false;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct UiAnimationLooped {
+    pub entity: Entity,
+    pub count: u32,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct UiAnimationFinished {
+    pub entity: Entity,
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn animate(self, animation: UiAnimation) -> Self {
+        self.insert(UiAnimationPlayer::new(animation))
+    }
+}
+
+pub(crate) type AnimatedComponents<'a> = (
+    Option<&'a mut Style>,
+    Option<&'a mut BackgroundColor>,
+    Option<&'a mut Text>,
+    Option<&'a mut UiImage>,
+    Option<&'a mut Visibility>,
+);
+
+pub(crate) fn apply_animation_value(
+    (style, background_color, text, image, visibility): &mut (
+        Option<Mut<Style>>,
+        Option<Mut<BackgroundColor>>,
+        Option<Mut<Text>>,
+        Option<Mut<UiImage>>,
+        Option<Mut<Visibility>>,
+    ),
+    property: AnimatedProperty,
+    value: AnimationValue,
+) {
+    match (property, value) {
+        (AnimatedProperty::BackgroundColor, AnimationValue::Color(color)) => {
+            if let Some(background_color) = background_color {
+                if background_color.0 != color {
+                    background_color.0 = color;
+                }
+            }
+        }
+        (AnimatedProperty::TextColor, AnimationValue::Color(color)) => {
+            if let Some(text) = text {
+                if text
+                    .sections
+                    .iter()
+                    .any(|section| section.style.color != color)
+                {
+                    for section in text.sections.iter_mut() {
+                        section.style.color = color;
+                    }
+                }
+            }
+        }
+        (AnimatedProperty::Length(field), AnimationValue::Val(val)) => {
+            if let Some(style) = style {
+                if *field.get_mut(style.bypass_change_detection()) != val {
+                    *field.get_mut(style) = val;
+                }
+            }
+        }
+        (AnimatedProperty::FlipX, AnimationValue::Bool(flip)) => {
+            if let Some(image) = image {
+                if image.flip_x != flip {
+                    image.flip_x = flip;
+                }
+            }
+        }
+        (AnimatedProperty::FlipY, AnimationValue::Bool(flip)) => {
+            if let Some(image) = image {
+                if image.flip_y != flip {
+                    image.flip_y = flip;
+                }
+            }
+        }
+        (AnimatedProperty::Visibility, AnimationValue::Visibility(value)) => {
+            if let Some(visibility) = visibility {
+                if **visibility != value {
+                    **visibility = value;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn play_ui_animations(
+    time: Res<Time>,
+    mut players: Query<(Entity, &mut UiAnimationPlayer, AnimatedComponents)>,
+    mut looped: EventWriter<UiAnimationLooped>,
+    mut finished: EventWriter<UiAnimationFinished>,
+) {
+    for (entity, mut player, mut components) in players.iter_mut() {
+        if player.paused || player.finished {
+            continue;
+        }
+        let player = &mut *player;
+        let animation = &player.animation;
+        player.elapsed += time.delta_seconds();
+
+        if player.elapsed < animation.delay {
+            continue;
+        }
+        let local = (player.elapsed - animation.delay) * animation.speed;
+        let (cycle, time, finished) = animation.playhead(local);
+        player.finished = finished;
+
+        while player.completed < cycle {
+            player.completed += 1;
+            looped.send(UiAnimationLooped {
+                entity,
+                count: player.completed,
+            });
+        }
+
+        for track in animation.tracks.iter() {
+            if let Some(value) = track.sample(time) {
+                apply_animation_value(&mut components, track.property, value);
+            }
+        }
+
+        if player.finished {
+            finished.send(UiAnimationFinished { entity });
+        }
+    }
+}
+
+pub struct UiAnimationPlugin;
+
+impl Plugin for UiAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<UiAnimationLooped>()
+            .add_event::<UiAnimationFinished>()
+            .add_systems(Update, play_ui_animations.after(UiStyleSet::Apply));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn advance(app: &mut App, seconds: f32) {
+        let mut time = app.world.resource_mut::<Time>();
+        let next = time.last_update().unwrap_or(time.startup()) + Duration::from_secs_f32(seconds);
+        time.update_with_instant(next);
+        app.update();
+    }
+
+    fn width(value: f32) -> AnimationValue {
+        AnimationValue::Val(Val::Px(value))
+    }
+
+    #[test]
+    fn tracks_sample_between_keyframes() {
+        let animation = UiAnimation::new()
+            .keyframe(0.5, AnimatedProperty::WIDTH, Val::Px(0.))
+            .keyframe(1.5, AnimatedProperty::WIDTH, Val::Px(100.))
+            .keyframe_eased(2.5, AnimatedProperty::WIDTH, Val::Px(200.), Easing::Linear)
+            .keyframe(1., AnimatedProperty::Visibility, Visibility::Hidden);
+        let [width_track, visibility_track] = &animation.tracks[..] else {
+            panic!("expected one track per property");
+        };
+
+        assert_eq!(width_track.sample(0.), Some(width(0.)));
+        assert_eq!(width_track.sample(1.), Some(width(50.)));
+        assert_eq!(width_track.sample(1.5), Some(width(100.)));
+        assert_eq!(width_track.sample(2.), Some(width(150.)));
+        assert_eq!(width_track.sample(3.), Some(width(200.)));
+        assert_eq!(
+            visibility_track.sample(2.),
+            Some(AnimationValue::Visibility(Visibility::Hidden))
+        );
+        assert_eq!(
+            AnimationTrack {
+                property: AnimatedProperty::WIDTH,
+                keyframes: Vec::new(),
+            }
+            .sample(1.),
+            None
+        );
+    }
+
+    #[test]
+    fn playhead_counts_cycles_and_reverses_odd_ping_pong_cycles() {
+        let animation = UiAnimation::new()
+            .keyframe(0., AnimatedProperty::WIDTH, Val::Px(0.))
+            .keyframe(2., AnimatedProperty::WIDTH, Val::Px(100.))
+            .repeat(AnimationRepeat::Times(3));
+        assert_eq!(animation.playhead(0.5), (0, 0.5, false));
+        assert_eq!(animation.playhead(2.5), (1, 0.5, false));
+        assert_eq!(animation.playhead(6.5), (2, 2., true));
+
+        let ping_pong = animation.clone().ping_pong(true);
+        assert_eq!(ping_pong.playhead(0.5), (0, 0.5, false));
+        assert_eq!(ping_pong.playhead(2.5), (1, 1.5, false));
+        assert_eq!(ping_pong.playhead(4.5), (2, 0.5, false));
+        assert_eq!(ping_pong.playhead(7.), (2, 2., true));
+
+        let two = ping_pong.repeat(AnimationRepeat::Times(2));
+        assert_eq!(two.playhead(5.), (1, 0., true));
+
+        let forever = UiAnimation::new()
+            .keyframe(1., AnimatedProperty::WIDTH, Val::Px(100.))
+            .repeat(AnimationRepeat::Forever);
+        assert_eq!(forever.playhead(10.25), (10, 0.25, false));
+    }
+
+    #[test]
+    fn players_apply_delay_speed_and_send_loop_events() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<UiAnimationLooped>()
+            .add_event::<UiAnimationFinished>()
+            .add_systems(Update, play_ui_animations);
+        let entity = app
+            .world
+            .spawn((
+                UiAnimationPlayer::new(
+                    UiAnimation::new()
+                        .keyframe(0., AnimatedProperty::WIDTH, Val::Px(0.))
+                        .keyframe(1., AnimatedProperty::WIDTH, Val::Px(100.))
+                        .repeat(AnimationRepeat::Times(3))
+                        .ping_pong(true)
+                        .delay(1.)
+                        .speed(2.),
+                ),
+                Style::default(),
+            ))
+            .id();
+        let width = |app: &App| app.world.get::<Style>(entity).unwrap().width;
+        let looped = |app: &App| {
+            let events = app.world.resource::<Events<UiAnimationLooped>>();
+            events
+                .iter_current_update_events()
+                .map(|event| event.count)
+                .collect::<Vec<_>>()
+        };
+
+        advance(&mut app, 0.);
+        advance(&mut app, 0.5);
+        assert_eq!(width(&app), Val::Auto);
+
+        advance(&mut app, 0.75);
+        assert_eq!(width(&app), Val::Px(50.));
+        assert!(looped(&app).is_empty());
+
+        // Skipping straight into the third cycle still reports both loops.
+        advance(&mut app, 1.);
+        assert_eq!(width(&app), Val::Px(50.));
+        assert_eq!(looped(&app), [1, 2]);
+
+        advance(&mut app, 1.);
+        assert_eq!(width(&app), Val::Px(100.));
+        assert!(looped(&app).is_empty());
+        assert!(app
+            .world
+            .get::<UiAnimationPlayer>(entity)
+            .unwrap()
+            .is_finished());
+        assert_eq!(
+            app.world
+                .resource::<Events<UiAnimationFinished>>()
+                .iter_current_update_events()
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{prelude::*, transform::TransformSystem, ui::UiSystem};
+
+use crate::{
+    Easing, InternalUiSpawner, Styler, UiBundleGenerator, UiBundleGeneratorStyler, UiComponent,
+};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AnimateLayout {
+    pub duration: f32,
+    pub easing: Easing,
+    layout_position: Option<Vec2>,
+    applied: Vec2,
+    from: Vec2,
+    elapsed: f32,
+}
+
+impl AnimateLayout {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            easing: Easing::EaseOut,
+            layout_position: None,
+            applied: Vec2::ZERO,
+            from: Vec2::ZERO,
+            elapsed: 0.,
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.from != Vec2::ZERO
+    }
+
+    fn offset(&self) -> Vec2 {
+        if self.duration <= 0. {
+            return Vec2::ZERO;
+        }
+        self.from * (1. - self.easing.apply(self.elapsed / self.duration))
+    }
+}
+
+impl Default for AnimateLayout {
+    fn default() -> Self {
+        Self::new(0.25)
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn animate_layout(self, duration: f32) -> Self {
+        self.insert(AnimateLayout::new(duration))
+    }
+}
+
+fn animate_layout_changes(
+    time: Res<Time>,
+    mut nodes: Query<(&mut AnimateLayout, &mut Transform), With<Node>>,
+) {
+    for (mut animation, mut transform) in nodes.iter_mut() {
+        let mut position = transform.translation.truncate();
+        if animation
+            .layout_position
+            .is_some_and(|layout_position| layout_position + animation.applied == position)
+        {
+            position -= animation.applied;
+        }
+        animation.applied = Vec2::ZERO;
+        let previous = animation.layout_position.replace(position);
+        if let Some(previous) = previous.filter(|previous| *previous != position) {
+            animation.from = previous + animation.offset() - position;
+            animation.elapsed = 0.;
+        }
+        if !animation.is_running() {
+            continue;
+        }
+
+        animation.elapsed += time.delta_seconds();
+        if animation.elapsed >= animation.duration {
+            animation.from = Vec2::ZERO;
+            continue;
+        }
+        animation.applied = animation.offset();
+        transform.translation += animation.applied.extend(0.);
+    }
+}
+
+pub struct LayoutAnimationPlugin;
+
+impl Plugin for LayoutAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            animate_layout_changes
+                .after(UiSystem::Layout)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
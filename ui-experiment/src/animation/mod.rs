@@ -0,0 +1,7 @@
+mod keyframes;
+mod layout;
+mod presence;
+
+pub use keyframes::*;
+pub use layout::*;
+pub use presence::*;
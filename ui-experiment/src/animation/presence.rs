@@ -0,0 +1,118 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{ecs::system::EntityCommands, hierarchy::HierarchyQueryExt, prelude::*};
+
+use crate::{
+    play_ui_animations, AnimationRepeat, InternalUiSpawner, Styler, UiAnimation, UiAnimationPlayer,
+    UiBundleGenerator, UiBundleGeneratorStyler, UiComponent,
+};
+
+#[derive(Component, Clone, Debug)]
+pub struct EnterAnimation(pub UiAnimation);
+
+#[derive(Component, Clone, Debug)]
+pub struct ExitAnimation(pub UiAnimation);
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Exiting;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct PendingDespawn;
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn on_enter(self, animation: UiAnimation) -> Self {
+        self.insert((
+            EnterAnimation(animation.clone()),
+            UiAnimationPlayer::new(animation),
+        ))
+    }
+
+    pub fn on_exit(self, animation: UiAnimation) -> Self {
+        self.insert(ExitAnimation(animation))
+    }
+}
+
+fn despawn_ui(root: Entity, world: &mut World) {
+    match world.get_entity(root) {
+        Some(entity) if !entity.contains::<PendingDespawn>() => {}
+        _ => return,
+    }
+
+    let mut subtree = vec![root];
+    let mut index = 0;
+    while let Some(entity) = subtree.get(index).copied() {
+        if let Some(children) = world.get::<Children>(entity) {
+            subtree.extend(children.iter().copied());
+        }
+        index += 1;
+    }
+
+    let exits: Vec<(Entity, UiAnimation)> = subtree
+        .into_iter()
+        .filter_map(|entity| {
+            world
+                .get::<ExitAnimation>(entity)
+                .map(|exit| (entity, exit.0.clone()))
+        })
+        .collect();
+    if exits.is_empty() {
+        world.entity_mut(root).despawn_recursive();
+        return;
+    }
+
+    for (entity, mut animation) in exits {
+        if matches!(animation.repeat, AnimationRepeat::Forever) {
+            animation.repeat = AnimationRepeat::Once;
+        }
+        world
+            .entity_mut(entity)
+            .remove::<EnterAnimation>()
+            .insert((UiAnimationPlayer::new(animation), Exiting));
+    }
+    world.entity_mut(root).insert(PendingDespawn);
+}
+
+pub trait DespawnUiExt {
+    fn despawn_ui(&mut self);
+}
+
+impl<'w, 's, 'a> DespawnUiExt for EntityCommands<'w, 's, 'a> {
+    fn despawn_ui(&mut self) {
+        self.add(despawn_ui);
+    }
+}
+
+fn despawn_after_exit(
+    mut commands: Commands,
+    pending: Query<Entity, With<PendingDespawn>>,
+    children: Query<&Children>,
+    exiting: Query<&UiAnimationPlayer, With<Exiting>>,
+) {
+    for root in pending.iter() {
+        let finished = std::iter::once(root)
+            .chain(children.iter_descendants(root))
+            .filter_map(|entity| exiting.get(entity).ok())
+            .all(|player| player.is_finished() || player.is_paused());
+        if finished {
+            commands.entity(root).despawn_recursive();
+        }
+    }
+}
+
+pub struct PresenceAnimationPlugin;
+
+impl Plugin for PresenceAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, despawn_after_exit.after(play_ui_animations));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{
+    ecs::reflect::{ReflectComponent, ReflectResource},
+    prelude::*,
+    reflect::{GetPath, TypeRegistration, TypeRegistry},
+    ui::UiSystem,
+};
+
+use crate::{
+    replace_text_value, InternalUiSpawner, Styler, UiBundleGenerator, UiBundleGeneratorStyler,
+    UiComponent,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingSource {
+    Entity(Entity),
+    Resource,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct TextBinding {
+    pub source: BindingSource,
+    pub path: String,
+    pub format: String,
+    last: Option<String>,
+    reported: bool,
+}
+
+impl TextBinding {
+    pub fn entity(entity: Entity, path: impl Into<String>, format: impl Into<String>) -> Self {
+        Self::new(BindingSource::Entity(entity), path, format)
+    }
+
+    pub fn resource(path: impl Into<String>, format: impl Into<String>) -> Self {
+        Self::new(BindingSource::Resource, path, format)
+    }
+
+    fn new(source: BindingSource, path: impl Into<String>, format: impl Into<String>) -> Self {
+        Self {
+            source,
+            path: path.into(),
+            format: format.into(),
+            last: None,
+            reported: false,
+        }
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn bind_text(
+        self,
+        entity: Entity,
+        path: impl Into<String>,
+        format: impl Into<String>,
+    ) -> Self {
+        self.insert(TextBinding::entity(entity, path, format))
+    }
+
+    pub fn bind_resource_text(self, path: impl Into<String>, format: impl Into<String>) -> Self {
+        self.insert(TextBinding::resource(path, format))
+    }
+}
+
+fn display_reflect(value: &dyn Reflect, precision: Option<usize>) -> String {
+    let float = |value: f64| match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => value.to_string(),
+    };
+    if let Some(value) = value.downcast_ref::<f32>() {
+        return float(*value as f64);
+    }
+    if let Some(value) = value.downcast_ref::<f64>() {
+        return float(*value);
+    }
+    macro_rules! display {
+        ($($t:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$t>() {
+                    return value.to_string();
+                }
+            )*
+        };
+    }
+    display!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, bool, char, String);
+    format!("{value:?}")
+}
+
+fn format_binding(format: &str, value: &dyn Reflect) -> String {
+    let Some(open) = format.find('{') else {
+        return format!("{format}{}", display_reflect(value, None));
+    };
+    let Some(close) = format[open..].find('}').map(|close| open + close) else {
+        return format!("{format}{}", display_reflect(value, None));
+    };
+    let precision = format[open + 1..close]
+        .strip_prefix(":.")
+        .and_then(|precision| precision.parse().ok());
+    format!(
+        "{}{}{}",
+        &format[..open],
+        display_reflect(value, precision),
+        &format[close + 1..]
+    )
+}
+
+fn binding_type<'r>(
+    registry: &'r TypeRegistry,
+    binding: &TextBinding,
+) -> Result<(&'r TypeRegistration, String), String> {
+    let type_name = binding
+        .path
+        .split_once('.')
+        .map_or(binding.path.as_str(), |(type_name, _)| type_name);
+    registry
+        .get_with_short_name(type_name)
+        .or_else(|| registry.get_with_name(type_name))
+        .map(|registration| (registration, type_name.to_string()))
+        .ok_or_else(|| format!("type `{type_name}` is not registered"))
+}
+
+fn source_changed(world: &World, registry: &TypeRegistry, binding: &TextBinding) -> Option<bool> {
+    let (registration, _) = binding_type(registry, binding).ok()?;
+    let ticks = match binding.source {
+        BindingSource::Entity(entity) => {
+            let id = world.components().get_id(registration.type_id())?;
+            world.get_entity(entity)?.get_change_ticks_by_id(id)?
+        }
+        BindingSource::Resource => {
+            let id = world.components().get_resource_id(registration.type_id())?;
+            world.storages().resources.get(id)?.get_ticks()?
+        }
+    };
+    Some(ticks.is_changed(world.last_change_tick(), world.read_change_tick()))
+}
+
+fn read_binding(
+    world: &World,
+    registry: &TypeRegistry,
+    binding: &TextBinding,
+) -> Result<String, String> {
+    let (registration, type_name) = binding_type(registry, binding)?;
+    let field_path = binding
+        .path
+        .split_once('.')
+        .map_or("", |(_, field_path)| field_path);
+
+    let value = match binding.source {
+        BindingSource::Entity(entity) => {
+            let entity = world
+                .get_entity(entity)
+                .ok_or_else(|| format!("entity {entity:?} does not exist"))?;
+            registration
+                .data::<ReflectComponent>()
+                .ok_or_else(|| format!("`{type_name}` does not reflect Component"))?
+                .reflect(entity)
+        }
+        BindingSource::Resource => registration
+            .data::<ReflectResource>()
+            .ok_or_else(|| format!("`{type_name}` does not reflect Resource"))?
+            .reflect(world),
+    }
+    .ok_or_else(|| format!("`{type_name}` is missing"))?;
+
+    let value = if field_path.is_empty() {
+        value
+    } else {
+        value
+            .reflect_path(field_path)
+            .map_err(|error| format!("invalid path `{field_path}`: {error:?}"))?
+    };
+    Ok(format_binding(&binding.format, value))
+}
+
+fn shows_value(text: &Text, value: &str) -> bool {
+    text.sections.len() == 1 && text.sections[0].value == value
+}
+
+type BindingQuery = QueryState<(
+    Entity,
+    Ref<'static, TextBinding>,
+    Option<Ref<'static, Text>>,
+)>;
+
+/// Exclusive because bindings reach arbitrary reflected components and resources. Sources are
+/// only read when their change ticks moved since the last run, when the binding itself changed,
+/// or when something else overwrote the bound text.
+fn update_text_bindings(world: &mut World, mut bindings: Local<BindingQuery>) {
+    if bindings.iter(world).next().is_none() {
+        return;
+    }
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let updates: Vec<(Entity, Result<String, String>)> = bindings
+        .iter(world)
+        .filter(|(_, binding, text)| {
+            binding.is_changed()
+                || source_changed(world, &registry, binding).unwrap_or(!binding.reported)
+                || text.as_ref().is_some_and(|text| {
+                    text.is_changed()
+                        && !binding
+                            .last
+                            .as_ref()
+                            .is_some_and(|last| shows_value(text, last))
+                })
+        })
+        .map(|(entity, binding, _)| (entity, read_binding(world, &registry, &binding)))
+        .collect();
+
+    for (entity, result) in updates {
+        let Some(mut binding) = world.get_mut::<TextBinding>(entity) else {
+            continue;
+        };
+        let value = match result {
+            Ok(value) => value,
+            Err(error) => {
+                if !binding.reported {
+                    binding.bypass_change_detection().reported = true;
+                    warn!("text binding `{}` on {entity:?}: {error}", binding.path);
+                }
+                continue;
+            }
+        };
+        let binding = binding.bypass_change_detection();
+        binding.reported = false;
+        binding.last = Some(value.clone());
+        if let Some(mut text) = world.get_mut::<Text>(entity) {
+            replace_text_value(&mut text, value);
+        }
+    }
+}
+
+pub struct TextBindingPlugin;
+
+impl Plugin for TextBindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_text_bindings.before(UiSystem::Layout));
+    }
+}
@@ -3,19 +3,20 @@ use bevy::{
     ui::{BackgroundColor, FocusPolicy, Style, UiImage, ZIndex},
 };
 
-use crate::{style::StyleComponentApplier, UiBundleGeneratorStyler};
+use crate::{style::StyleComponentApplier, UiBundleGeneratorStyler, UiLengths};
 
-#[derive(Component)]
+#[derive(Component, Clone, Default)]
 pub struct ButtonNode;
 
-#[derive(Component)]
+#[derive(Component, Clone, Default)]
 pub struct ClickedEventEmitter<T: Send + Sync>(T);
 
-#[derive(Bundle)]
-pub struct UiButtonBundle<T: Send + Sync + 'static> {
+#[derive(Bundle, Clone, Default)]
+pub struct UiButtonBundle<T: Send + Sync + 'static = ()> {
     node_bundle: ButtonBundle,
     marker: ButtonNode,
     clicked: ClickedEventEmitter<T>,
+    lengths: UiLengths,
 }
 
 impl StyleComponentApplier<BackgroundColor> for ButtonBundle {
@@ -60,8 +61,71 @@ impl StyleComponentApplier<UiImage> for ButtonBundle {
     }
 }
 
+// Like the query tuples, a plain `ButtonBundle` has nowhere to keep relative lengths.
+impl StyleComponentApplier<UiLengths> for ButtonBundle {
+    fn get_component<T: FnMut(&mut UiLengths)>(self, mut apply: T) -> Self {
+        apply(&mut UiLengths::default());
+        self
+    }
+}
+
 impl UiBundleGeneratorStyler for ButtonBundle {
     fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
         styler.style(self)
     }
 }
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<BackgroundColor> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut BackgroundColor)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.background_color);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<Style> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut Style)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.style);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<FocusPolicy> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut FocusPolicy)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.focus_policy);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<ZIndex> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut ZIndex)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.z_index);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<Visibility> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut Visibility)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.visibility);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<UiImage> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut UiImage)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.node_bundle.image);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> StyleComponentApplier<UiLengths> for UiButtonBundle<T> {
+    fn get_component<F: FnMut(&mut UiLengths)>(mut self, mut apply: F) -> Self {
+        apply(&mut self.lengths);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> UiBundleGeneratorStyler for UiButtonBundle<T> {
+    fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
+        styler.style(self)
+    }
+}
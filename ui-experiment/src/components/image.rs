@@ -3,7 +3,7 @@ use bevy::{
     ui::{BackgroundColor, FocusPolicy, Style, UiImage, ZIndex},
 };
 
-use crate::{style::StyleComponentApplier, UIQuery, UiBundleGeneratorStyler};
+use crate::{style::StyleComponentApplier, UIQuery, UiBundleGeneratorStyler, UiLengths};
 
 pub type ImageComponents<'a> = (
     &'a mut Style,
@@ -23,6 +23,7 @@ pub struct ImageNode;
 pub struct UiImageBundle {
     pub node_bundle: ImageBundle,
     pub marker: ImageNode,
+    pub lengths: UiLengths,
 }
 
 impl Clone for UiImageBundle {
@@ -43,6 +44,7 @@ impl Clone for UiImageBundle {
                 z_index: self.node_bundle.z_index,
             },
             marker: self.marker.clone(),
+            lengths: self.lengths.clone(),
         }
     }
 }
@@ -89,6 +91,13 @@ impl StyleComponentApplier<UiImage> for UiImageBundle {
     }
 }
 
+impl StyleComponentApplier<UiLengths> for UiImageBundle {
+    fn get_component<T: FnMut(&mut UiLengths)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.lengths);
+        self
+    }
+}
+
 impl UiBundleGeneratorStyler for UiImageBundle {
     fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
         styler.style(self)
@@ -191,6 +200,22 @@ impl<'a> StyleComponentApplier<UiImage>
     }
 }
 
+impl<'a> StyleComponentApplier<UiLengths>
+    for (
+        Mut<'a, Style>,
+        Mut<'a, BackgroundColor>,
+        Mut<'a, FocusPolicy>,
+        Mut<'a, ZIndex>,
+        Mut<'a, Visibility>,
+        Mut<'a, UiImage>,
+    )
+{
+    fn get_component<T: FnMut(&mut UiLengths)>(self, mut apply: T) -> Self {
+        apply(&mut UiLengths::default());
+        self
+    }
+}
+
 impl<'a> UiBundleGeneratorStyler
     for (
         Mut<'a, Style>,
@@ -1,9 +1,11 @@
 pub mod button;
 pub mod image;
 pub mod node;
+pub mod state;
 pub mod text;
 
 pub use button::*;
 pub use image::*;
 pub use node::*;
+pub use state::*;
 pub use text::*;
@@ -3,7 +3,7 @@ use bevy::{
     ui::{BackgroundColor, FocusPolicy, Style, ZIndex},
 };
 
-use crate::{style::StyleComponentApplier, UIQuery, UiBundleGeneratorStyler};
+use crate::{style::StyleComponentApplier, UIQuery, UiBundleGeneratorStyler, UiLengths};
 
 pub type NodeComponents<'a> = (
     &'a mut Style,
@@ -13,6 +13,15 @@ pub type NodeComponents<'a> = (
     &'a mut Visibility,
 );
 
+pub type NodeComponentsMut<'a> = (
+    Mut<'a, Style>,
+    Mut<'a, BackgroundColor>,
+    Mut<'a, FocusPolicy>,
+    Mut<'a, ZIndex>,
+    Mut<'a, Visibility>,
+    Mut<'a, UiLengths>,
+);
+
 pub type NodeQuery<'w, 's, 'a, T> = UIQuery<'w, 's, 'a, T, NodeComponents<'a>, UiNode>;
 
 #[derive(Component, Clone, Default)]
@@ -22,6 +31,7 @@ pub struct UiNode;
 pub struct UiNodeBundle {
     node_bundle: NodeBundle,
     marker: UiNode,
+    lengths: UiLengths,
 }
 
 impl StyleComponentApplier<BackgroundColor> for UiNodeBundle {
@@ -59,6 +69,13 @@ impl StyleComponentApplier<Visibility> for UiNodeBundle {
     }
 }
 
+impl StyleComponentApplier<UiLengths> for UiNodeBundle {
+    fn get_component<T: FnMut(&mut UiLengths)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.lengths);
+        self
+    }
+}
+
 impl UiBundleGeneratorStyler for UiNodeBundle {
     fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
         styler.style(self)
@@ -140,6 +157,23 @@ impl<'a> StyleComponentApplier<Visibility>
     }
 }
 
+// Query tuples don't carry `UiLengths`, so relative lengths set through them keep their
+// provisional px value instead of following font size changes.
+impl<'a> StyleComponentApplier<UiLengths>
+    for (
+        Mut<'a, Style>,
+        Mut<'a, BackgroundColor>,
+        Mut<'a, FocusPolicy>,
+        Mut<'a, ZIndex>,
+        Mut<'a, Visibility>,
+    )
+{
+    fn get_component<T: FnMut(&mut UiLengths)>(self, mut apply: T) -> Self {
+        apply(&mut UiLengths::default());
+        self
+    }
+}
+
 impl<'a> UiBundleGeneratorStyler
     for (
         Mut<'a, Style>,
@@ -153,3 +187,51 @@ impl<'a> UiBundleGeneratorStyler
         styler.style(self)
     }
 }
+
+impl<'a> StyleComponentApplier<Style> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut Style)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.0);
+        self
+    }
+}
+
+impl<'a> StyleComponentApplier<BackgroundColor> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut BackgroundColor)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.1);
+        self
+    }
+}
+
+impl<'a> StyleComponentApplier<FocusPolicy> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut FocusPolicy)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.2);
+        self
+    }
+}
+
+impl<'a> StyleComponentApplier<ZIndex> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut ZIndex)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.3);
+        self
+    }
+}
+
+impl<'a> StyleComponentApplier<Visibility> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut Visibility)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.4);
+        self
+    }
+}
+
+impl<'a> StyleComponentApplier<UiLengths> for NodeComponentsMut<'a> {
+    fn get_component<T: FnMut(&mut UiLengths)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.5);
+        self
+    }
+}
+
+impl<'a> UiBundleGeneratorStyler for NodeComponentsMut<'a> {
+    fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
+        styler.style(self)
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::{Component, Reflect, ReflectComponent};
+
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Disabled;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct InheritedDisabled;
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Checked(pub bool);
@@ -4,7 +4,10 @@ use bevy::{
     ui::{BackgroundColor, FocusPolicy, Style, ZIndex},
 };
 
-use crate::{style::StyleComponentApplier, UIQuery, UiBundleGeneratorStyler};
+use crate::{
+    style::StyleComponentApplier, FontSelections, TextApplier, TextOverflow, UIQuery,
+    UiBundleGeneratorStyler, UiLengths,
+};
 
 pub type TextComponents<'a> = (
     &'a mut Style,
@@ -24,6 +27,9 @@ pub struct TextNode;
 pub struct UiTextBundle {
     pub node_bundle: TextBundle,
     pub marker: TextNode,
+    pub lengths: UiLengths,
+    pub overflow: TextOverflow,
+    pub fonts: FontSelections,
 }
 
 impl Clone for UiTextBundle {
@@ -45,6 +51,9 @@ impl Clone for UiTextBundle {
                 text_flags: self.node_bundle.text_flags.clone(),
             },
             marker: self.marker.clone(),
+            lengths: self.lengths.clone(),
+            overflow: self.overflow.clone(),
+            fonts: self.fonts.clone(),
         }
     }
 }
@@ -91,9 +100,30 @@ impl StyleComponentApplier<Text> for UiTextBundle {
     }
 }
 
+impl StyleComponentApplier<UiLengths> for UiTextBundle {
+    fn get_component<T: FnMut(&mut UiLengths)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.lengths);
+        self
+    }
+}
+
+impl StyleComponentApplier<TextOverflow> for UiTextBundle {
+    fn get_component<T: FnMut(&mut TextOverflow)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.overflow);
+        self
+    }
+}
+
+impl StyleComponentApplier<FontSelections> for UiTextBundle {
+    fn get_component<T: FnMut(&mut FontSelections)>(mut self, mut apply: T) -> Self {
+        apply(&mut self.fonts);
+        self
+    }
+}
+
 impl UiBundleGeneratorStyler for UiTextBundle {
     fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
-        let s = styler.text_style(self);
+        let s = styler.text_style(self).style_sections(styler, 0);
         styler.style(s)
     }
 }
@@ -194,6 +224,22 @@ impl<'a> StyleComponentApplier<Text>
     }
 }
 
+impl<'a> StyleComponentApplier<UiLengths>
+    for (
+        Mut<'a, Style>,
+        Mut<'a, BackgroundColor>,
+        Mut<'a, FocusPolicy>,
+        Mut<'a, ZIndex>,
+        Mut<'a, Visibility>,
+        Mut<'a, Text>,
+    )
+{
+    fn get_component<T: FnMut(&mut UiLengths)>(self, mut apply: T) -> Self {
+        apply(&mut UiLengths::default());
+        self
+    }
+}
+
 impl<'a> UiBundleGeneratorStyler
     for (
         Mut<'a, Style>,
@@ -205,7 +251,7 @@ impl<'a> UiBundleGeneratorStyler
     )
 {
     fn apply_styler<S: crate::Styler>(self, styler: &S) -> Self {
-        let s = styler.text_style(self);
+        let s = styler.text_style(self).style_sections(styler, 0);
         styler.style(s)
     }
 }
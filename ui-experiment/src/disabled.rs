@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{
+    ecs::query::Has, hierarchy::HierarchyQueryExt, prelude::*, ui::UiSystem, utils::HashSet,
+};
+
+use crate::{
+    Disabled, InheritedDisabled, InternalUiSpawner, StateStyles, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiComponent, UiFocus, UiState,
+};
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn disabled(self, disabled: bool) -> Self {
+        if disabled {
+            self.insert(Disabled)
+        } else {
+            self
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn propagate_disabled(
+    mut commands: Commands,
+    mut removed_disabled: RemovedComponents<Disabled>,
+    mut removed_children: RemovedComponents<Children>,
+    mut removed_parents: RemovedComponents<Parent>,
+    changed: Query<(), Or<(Changed<Disabled>, Changed<Children>, Changed<Parent>)>>,
+    disabled: Query<Entity, With<Disabled>>,
+    inherited: Query<Entity, With<InheritedDisabled>>,
+    children: Query<&Children>,
+) {
+    let removed = removed_disabled.iter().count()
+        + removed_children.iter().count()
+        + removed_parents.iter().count();
+    if removed == 0 && changed.is_empty() {
+        return;
+    }
+    let descendants: HashSet<Entity> = disabled
+        .iter()
+        .flat_map(|entity| children.iter_descendants(entity))
+        .collect();
+    for entity in inherited.iter() {
+        if !descendants.contains(&entity) {
+            commands.entity(entity).remove::<InheritedDisabled>();
+        }
+    }
+    for entity in descendants {
+        if !inherited.contains(entity) {
+            commands.entity(entity).insert(InheritedDisabled);
+        }
+    }
+}
+
+// Only presses are suppressed. Bevy's focus system re-hovers a node every frame its
+// interaction is `None`, so clearing hovers would churn `Changed<Interaction>` and keep
+// tooltips on disabled nodes from ever showing.
+fn suppress_disabled_interaction(
+    mut interactions: Query<&mut Interaction, Or<(With<Disabled>, With<InheritedDisabled>)>>,
+) {
+    for mut interaction in interactions.iter_mut() {
+        if *interaction == Interaction::Pressed {
+            *interaction = Interaction::Hovered;
+        }
+    }
+}
+
+fn blur_disabled(
+    mut focus: ResMut<UiFocus>,
+    disabled: Query<(), Or<(With<Disabled>, With<InheritedDisabled>)>>,
+) {
+    if focus
+        .focused()
+        .is_some_and(|focused| disabled.contains(focused))
+    {
+        focus.clear();
+    }
+}
+
+fn track_disabled_state(
+    mut commands: Commands,
+    disabled: Query<
+        (Entity, Has<StateStyles>),
+        (
+            Or<(With<Disabled>, With<InheritedDisabled>)>,
+            Without<UiState>,
+            With<Node>,
+        ),
+    >,
+) {
+    for (entity, has_styles) in disabled.iter() {
+        let mut entity = commands.entity(entity);
+        entity.insert(UiState::default());
+        if !has_styles {
+            entity.insert(StateStyles::default());
+        }
+    }
+}
+
+pub struct DisabledPlugin;
+
+impl Plugin for DisabledPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            (
+                propagate_disabled,
+                apply_deferred,
+                suppress_disabled_interaction,
+                blur_disabled,
+            )
+                .chain()
+                .after(UiSystem::Focus),
+        )
+        .add_systems(Update, track_disabled_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_nodes_keep_hover_but_never_press() {
+        let mut app = App::new();
+        app.add_systems(Update, suppress_disabled_interaction);
+        let disabled = app.world.spawn((Disabled, Interaction::Pressed)).id();
+        let inherited = app
+            .world
+            .spawn((InheritedDisabled, Interaction::Hovered))
+            .id();
+        let enabled = app.world.spawn(Interaction::Pressed).id();
+        app.update();
+
+        let interaction = |entity| *app.world.get::<Interaction>(entity).unwrap();
+        assert_eq!(interaction(disabled), Interaction::Hovered);
+        assert_eq!(interaction(inherited), Interaction::Hovered);
+        assert_eq!(interaction(enabled), Interaction::Pressed);
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, ui::UiStack};
+
+use crate::{Disabled, InheritedDisabled};
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Focusable;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct FocusTrap;
+
+#[derive(Resource, Debug, Default)]
+pub struct UiFocus {
+    focused: Option<Entity>,
+    traps: Vec<Entity>,
+}
+
+impl UiFocus {
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.focused == Some(entity)
+    }
+
+    pub fn focus(&mut self, entity: Entity) {
+        self.focused = Some(entity);
+    }
+
+    pub fn clear(&mut self) {
+        self.focused = None;
+    }
+
+    pub fn active_trap(&self) -> Option<Entity> {
+        self.traps.last().copied()
+    }
+}
+
+type FocusableFilter = (
+    With<Focusable>,
+    Without<Disabled>,
+    Without<InheritedDisabled>,
+);
+
+pub(crate) fn is_within(entity: Entity, root: Entity, parents: &Query<&Parent>) -> bool {
+    entity == root
+        || parents
+            .iter_ancestors(entity)
+            .any(|ancestor| ancestor == root)
+}
+
+fn focus_candidates(
+    focus: &UiFocus,
+    ui_stack: &UiStack,
+    focusables: &Query<(), FocusableFilter>,
+    parents: &Query<&Parent>,
+) -> Vec<Entity> {
+    let trap = focus.active_trap();
+    ui_stack
+        .uinodes
+        .iter()
+        .copied()
+        .filter(|entity| focusables.contains(*entity))
+        .filter(|entity| trap.map_or(true, |trap| is_within(*entity, trap, parents)))
+        .collect()
+}
+
+fn track_focus_traps(
+    mut focus: ResMut<UiFocus>,
+    added: Query<Entity, Added<FocusTrap>>,
+    mut removed: RemovedComponents<FocusTrap>,
+) {
+    for entity in removed.iter() {
+        focus.traps.retain(|trap| *trap != entity);
+    }
+    for entity in added.iter() {
+        focus.traps.push(entity);
+    }
+}
+
+fn focus_on_press(
+    mut focus: ResMut<UiFocus>,
+    pressed: Query<(Entity, &Interaction), (Changed<Interaction>, With<Focusable>)>,
+) {
+    for (entity, interaction) in pressed.iter() {
+        if *interaction == Interaction::Pressed {
+            focus.focus(entity);
+        }
+    }
+}
+
+fn navigate_focus(
+    mut focus: ResMut<UiFocus>,
+    input: Res<Input<KeyCode>>,
+    ui_stack: Res<UiStack>,
+    focusables: Query<(), FocusableFilter>,
+    parents: Query<&Parent>,
+) {
+    let candidates = focus_candidates(&focus, &ui_stack, &focusables, &parents);
+    let current = focus
+        .focused
+        .and_then(|focused| candidates.iter().position(|entity| *entity == focused));
+
+    let next = if input.just_pressed(KeyCode::Tab) {
+        let backwards = input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        match (current, backwards) {
+            (Some(current), false) => Some((current + 1) % candidates.len()),
+            (Some(current), true) => Some((current + candidates.len() - 1) % candidates.len()),
+            (None, false) => Some(0),
+            (None, true) => candidates.len().checked_sub(1),
+        }
+    } else if current.is_none() && focus.active_trap().is_some() {
+        Some(0)
+    } else {
+        return;
+    };
+
+    match next.and_then(|next| candidates.get(next)) {
+        Some(entity) => focus.focus(*entity),
+        None => focus.clear(),
+    }
+}
+
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFocus>().add_systems(
+            Update,
+            (track_focus_traps, focus_on_press, navigate_focus).chain(),
+        );
+    }
+}
@@ -1,20 +1,62 @@
+pub mod animation;
+pub mod binding;
 pub mod components;
+pub mod disabled;
+pub mod focus;
+pub mod localization;
 pub mod style;
 pub mod ui_bundle_spawner;
 pub mod ui_id;
 mod ui_schedule;
+pub mod widgets;
 
 use bevy::prelude::*;
 
+pub use animation::*;
+pub use binding::*;
 pub use components::*;
+pub use disabled::*;
+pub use focus::*;
+pub use localization::*;
 pub use style::*;
 pub use ui_bundle_spawner::*;
 pub use ui_id::*;
+pub use widgets::*;
 
 pub use ui_schedule::*;
 
 pub struct EcsUiPlugin;
 
 impl Plugin for EcsUiPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            (
+                RelativeUnitsPlugin,
+                StyleLayersPlugin,
+                ViewportPlugin,
+                ThemePlugin,
+                StylesheetPlugin,
+                ClassesPlugin,
+                UtilityClassesPlugin,
+                StateStylesPlugin,
+                TransitionPlugin,
+                RichTextPlugin,
+                TextSectionStylePlugin,
+                FontFamilyPlugin,
+                TextOverflowPlugin,
+            ),
+            (
+                UiAnimationPlugin,
+                PresenceAnimationPlugin,
+                LayoutAnimationPlugin,
+            ),
+            DisabledPlugin,
+            FocusPlugin,
+            LocalizationPlugin,
+            TextBindingPlugin,
+            TooltipPlugin,
+            CollapsiblePlugin,
+            TablePlugin,
+        ));
+    }
 }
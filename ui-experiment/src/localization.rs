@@ -0,0 +1,632 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    ui::UiSystem,
+    utils::{BoxedFuture, HashMap, HashSet},
+};
+
+use crate::{
+    replace_text_value, ExternalUiSpawner, InternalUiSpawner, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiComponent, UiTextBundle,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocValue {
+    Number(f64),
+    String(String),
+}
+
+impl std::fmt::Display for LocValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocValue::Number(value) if value.fract() == 0. && value.abs() < 1e15 => {
+                write!(f, "{}", *value as i64)
+            }
+            LocValue::Number(value) => write!(f, "{value}"),
+            LocValue::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_number_loc_value {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for LocValue {
+                fn from(value: $t) -> Self {
+                    LocValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_loc_value!(i32, i64, u32, u64, usize, f32);
+
+impl From<f64> for LocValue {
+    fn from(value: f64) -> Self {
+        LocValue::Number(value)
+    }
+}
+
+impl From<&str> for LocValue {
+    fn from(value: &str) -> Self {
+        LocValue::String(value.to_string())
+    }
+}
+
+impl From<String> for LocValue {
+    fn from(value: String) -> Self {
+        LocValue::String(value)
+    }
+}
+
+pub fn plural_category(language: &str, value: f64) -> &'static str {
+    let language = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let integer = value.fract() == 0.;
+    let i = value.abs() as u64;
+    let (mod10, mod100) = (i % 10, i % 100);
+    match language.as_str() {
+        "ja" | "ko" | "zh" | "th" | "vi" | "id" => "other",
+        "fr" | "pt" => {
+            if value.abs() < 2. {
+                "one"
+            } else {
+                "other"
+            }
+        }
+        "ru" | "uk" | "be" | "sr" | "hr" | "bs" => match (integer, mod10, mod100) {
+            (false, _, _) => "other",
+            (true, 1, m) if m != 11 => "one",
+            (true, 2..=4, m) if !(12..=14).contains(&m) => "few",
+            _ => "many",
+        },
+        "pl" => match (integer, i, mod10, mod100) {
+            (false, ..) => "other",
+            (true, 1, ..) => "one",
+            (true, _, 2..=4, m) if !(12..=14).contains(&m) => "few",
+            _ => "many",
+        },
+        "cs" | "sk" => match (integer, i) {
+            (false, _) => "many",
+            (true, 1) => "one",
+            (true, 2..=4) => "few",
+            _ => "other",
+        },
+        "ar" => match (integer, i, mod100) {
+            (false, ..) => "other",
+            (true, 0, _) => "zero",
+            (true, 1, _) => "one",
+            (true, 2, _) => "two",
+            (true, _, 3..=10) => "few",
+            (true, _, 11..=99) => "many",
+            _ => "other",
+        },
+        _ => {
+            if integer && i == 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum PatternElement {
+    Text(String),
+    Variable(String),
+    Select {
+        selector: String,
+        variants: Vec<(String, Vec<PatternElement>)>,
+        default: usize,
+    },
+}
+
+fn find_closing(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_pattern(source: &str) -> Result<Vec<PatternElement>, String> {
+    let mut elements = Vec::new();
+    let mut rest = source;
+    while let Some(open) = rest.find('{') {
+        if open > 0 {
+            elements.push(PatternElement::Text(rest[..open].to_string()));
+        }
+        let close = find_closing(rest, open).ok_or_else(|| "unclosed `{`".to_string())?;
+        elements.push(parse_placeable(rest[open + 1..close].trim())?);
+        rest = &rest[close + 1..];
+    }
+    if !rest.is_empty() {
+        elements.push(PatternElement::Text(rest.to_string()));
+    }
+    Ok(elements)
+}
+
+fn parse_placeable(source: &str) -> Result<PatternElement, String> {
+    if let Some((selector, body)) = source.split_once("->") {
+        let Some(selector) = selector.trim().strip_prefix('$') else {
+            return Err(format!("select expression on `{}`", selector.trim()));
+        };
+        let mut variants = Vec::new();
+        let mut default = None;
+        for line in body.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (is_default, line) = match line.strip_prefix('*') {
+                Some(line) => (true, line),
+                None => (false, line),
+            };
+            let Some((key, value)) = line.strip_prefix('[').and_then(|line| line.split_once(']'))
+            else {
+                return Err(format!("expected `[key] value`, found `{line}`"));
+            };
+            if is_default {
+                default = Some(variants.len());
+            }
+            variants.push((key.trim().to_string(), parse_pattern(value.trim())?));
+        }
+        let Some(default) = default else {
+            return Err(format!("select on `${selector}` has no default variant"));
+        };
+        return Ok(PatternElement::Select {
+            selector: selector.trim().to_string(),
+            variants,
+            default,
+        });
+    }
+
+    if let Some(name) = source.strip_prefix('$') {
+        return Ok(PatternElement::Variable(name.trim().to_string()));
+    }
+    if let Some(literal) = source
+        .strip_prefix('"')
+        .and_then(|source| source.strip_suffix('"'))
+    {
+        return Ok(PatternElement::Text(literal.to_string()));
+    }
+    Err(format!("unsupported placeable `{{ {source} }}`"))
+}
+
+fn format_pattern(
+    elements: &[PatternElement],
+    args: &[(String, LocValue)],
+    language: &str,
+    output: &mut String,
+) {
+    let arg = |name: &str| {
+        args.iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, value)| value)
+    };
+    for element in elements {
+        match element {
+            PatternElement::Text(text) => output.push_str(text),
+            PatternElement::Variable(name) => match arg(name) {
+                Some(value) => output.push_str(&value.to_string()),
+                None => output.push_str(&format!("{{${name}}}")),
+            },
+            PatternElement::Select {
+                selector,
+                variants,
+                default,
+            } => {
+                let value = arg(selector);
+                let exact = variants.iter().position(|(key, _)| match value {
+                    Some(LocValue::Number(number)) => key.parse::<f64>().ok() == Some(*number),
+                    Some(LocValue::String(string)) => key == string,
+                    None => false,
+                });
+                let plural = match value {
+                    Some(LocValue::Number(number)) => {
+                        let category = plural_category(language, *number);
+                        variants.iter().position(|(key, _)| key == category)
+                    }
+                    _ => None,
+                };
+                let (_, pattern) = &variants[exact.or(plural).unwrap_or(*default)];
+                format_pattern(pattern, args, language, output);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LocaleError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "locale error on line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for LocaleError {}
+
+#[derive(Clone, Debug, Default, TypeUuid, TypePath)]
+#[uuid = "c3f7d2a1-6b4e-4c8f-a5d9-1e2b7f0c9a36"]
+pub struct Locale {
+    messages: HashMap<String, Vec<PatternElement>>,
+}
+
+impl Locale {
+    pub fn parse(source: &str) -> Result<Self, LocaleError> {
+        let mut messages = HashMap::new();
+        let mut current: Option<(String, String, usize)> = None;
+
+        let mut finish = |current: Option<(String, String, usize)>| -> Result<(), LocaleError> {
+            let Some((key, value, line)) = current else {
+                return Ok(());
+            };
+            let pattern =
+                parse_pattern(value.trim()).map_err(|message| LocaleError { line, message })?;
+            messages.insert(key, pattern);
+            Ok(())
+        };
+
+        for (index, line) in source.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) {
+                if let Some((_, value, _)) = current.as_mut() {
+                    value.push('\n');
+                    value.push_str(line.trim());
+                    continue;
+                }
+            }
+            finish(current.take())?;
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(LocaleError {
+                    line: index + 1,
+                    message: format!("expected `key = value`, found `{}`", line.trim()),
+                });
+            };
+            let key = key.trim();
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return Err(LocaleError {
+                    line: index + 1,
+                    message: format!("invalid key `{key}`"),
+                });
+            }
+            current = Some((key.to_string(), value.trim().to_string(), index + 1));
+        }
+        finish(current)?;
+
+        Ok(Self { messages })
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    pub fn format(&self, key: &str, args: &[(String, LocValue)], language: &str) -> Option<String> {
+        let pattern = self.messages.get(key)?;
+        let mut output = String::new();
+        format_pattern(pattern, args, language, &mut output);
+        Some(output)
+    }
+}
+
+#[derive(Default)]
+pub struct LocaleLoader;
+
+impl AssetLoader for LocaleLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let source = std::str::from_utf8(bytes)?;
+            let locale = Locale::parse(source)?;
+            load_context.set_default_asset(LoadedAsset::new(locale));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Locales {
+    locales: HashMap<String, Handle<Locale>>,
+    active: String,
+    fallback: Option<String>,
+}
+
+impl Locales {
+    pub fn add(&mut self, language: impl Into<String>, locale: Handle<Locale>) {
+        let language = language.into();
+        if self.active.is_empty() {
+            self.active = language.clone();
+        }
+        self.locales.insert(language, locale);
+    }
+
+    pub fn active(&self) -> &str {
+        &self.active
+    }
+
+    pub fn set_active(&mut self, language: impl Into<String>) {
+        self.active = language.into();
+    }
+
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+
+    pub fn set_fallback(&mut self, language: impl Into<String>) {
+        self.fallback = Some(language.into());
+    }
+
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.locales.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, language: &str) -> Option<&Handle<Locale>> {
+        self.locales.get(language)
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct LocalizedText {
+    key: String,
+    args: Vec<(String, LocValue)>,
+}
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl Into<LocValue>) -> Self {
+        self.set_arg(name, value);
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn set_key(&mut self, key: impl Into<String>) {
+        self.key = key.into();
+    }
+
+    pub fn set_arg(&mut self, name: impl Into<String>, value: impl Into<LocValue>) {
+        let name = name.into();
+        let value = value.into();
+        match self.args.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.args.push((name, value)),
+        }
+    }
+}
+
+pub trait LocalizedTextSpawner<'w, 's, St: Styler>: ExternalUiSpawner<'w, 's, St> {
+    fn loc_text<'a>(
+        &'a mut self,
+        key: impl Into<String>,
+    ) -> UiComponent<'w, 's, 'a, UiTextBundle, Self::InternalSpawner, St, usize> {
+        let key = key.into();
+        self.text(key.clone()).insert(LocalizedText::new(key))
+    }
+}
+
+impl<'w, 's, St: Styler, E: ExternalUiSpawner<'w, 's, St>> LocalizedTextSpawner<'w, 's, St> for E {}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn loc_arg(self, name: impl Into<String>, value: impl Into<LocValue>) -> Self {
+        let name = name.into();
+        let value = value.into();
+        self.with_inserter(move |commands| {
+            commands.add(move |entity: Entity, world: &mut World| {
+                if let Some(mut localized) = world.get_mut::<LocalizedText>(entity) {
+                    localized.set_arg(name, value);
+                }
+            });
+        })
+    }
+}
+
+fn localize_texts(
+    locales: Res<Locales>,
+    assets: Res<Assets<Locale>>,
+    mut events: EventReader<AssetEvent<Locale>>,
+    mut texts: Query<(Ref<LocalizedText>, &mut Text)>,
+    mut reported: Local<HashSet<(String, String)>>,
+) {
+    if locales.is_changed() && !locales.active.is_empty() && locales.get(&locales.active).is_none()
+    {
+        warn!(
+            "active locale `{}` is not registered; available: {:?}",
+            locales.active,
+            locales.languages().collect::<Vec<_>>()
+        );
+    }
+
+    let refresh_all = locales.is_changed() || events.iter().last().is_some();
+    let active = locales
+        .get(&locales.active)
+        .and_then(|handle| assets.get(handle));
+    let fallback = locales
+        .fallback
+        .as_deref()
+        .and_then(|language| Some((language, assets.get(locales.get(language)?)?)));
+
+    if active.is_none() && fallback.is_none() {
+        return;
+    }
+
+    for (localized, mut text) in texts.iter_mut() {
+        if !refresh_all && !localized.is_changed() {
+            continue;
+        }
+        let value = active
+            .and_then(|locale| locale.format(&localized.key, &localized.args, &locales.active))
+            .or_else(|| {
+                fallback.and_then(|(language, locale)| {
+                    locale.format(&localized.key, &localized.args, language)
+                })
+            });
+        let value = value.unwrap_or_else(|| {
+            if reported.insert((locales.active.clone(), localized.key.clone())) {
+                warn!(
+                    "missing localization for `{}` in locale `{}`",
+                    localized.key, locales.active
+                );
+            }
+            localized.key.clone()
+        });
+
+        replace_text_value(&mut text, value);
+    }
+}
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Locale>()
+            .init_asset_loader::<LocaleLoader>()
+            .init_resource::<Locales>()
+            .add_systems(PostUpdate, localize_texts.before(UiSystem::Layout));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: impl IntoIterator<Item = (&'static str, LocValue)>) -> Vec<(String, LocValue)> {
+        values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+
+    const SOURCE: &str = "
+# comment
+greeting = Hello, { $name }! You have { $count } items.
+emails = { $count ->
+    [0] No emails
+    [one] One email
+   *[other] { $count } emails
+    }
+pronoun = { $gender ->
+    [female] her
+    [male] his
+   *[other] their
+    }
+";
+
+    #[test]
+    fn formats_arguments() {
+        let locale = Locale::parse(SOURCE).unwrap();
+        let format =
+            |values: Vec<(&'static str, LocValue)>| locale.format("greeting", &args(values), "en");
+        assert_eq!(
+            format(vec![("name", "Ada".into()), ("count", 3.into())]).as_deref(),
+            Some("Hello, Ada! You have 3 items.")
+        );
+        assert_eq!(
+            format(vec![("name", "Ada".into()), ("count", 2.5.into())]).as_deref(),
+            Some("Hello, Ada! You have 2.5 items.")
+        );
+        assert_eq!(
+            format(vec![("count", 1.into())]).as_deref(),
+            Some("Hello, {$name}! You have 1 items.")
+        );
+        assert_eq!(locale.format("missing", &[], "en"), None);
+    }
+
+    #[test]
+    fn selects_string_variants() {
+        let locale = Locale::parse(SOURCE).unwrap();
+        let format =
+            |gender: &str| locale.format("pronoun", &args([("gender", gender.into())]), "en");
+        assert_eq!(format("female").as_deref(), Some("her"));
+        assert_eq!(format("male").as_deref(), Some("his"));
+        assert_eq!(format("unknown").as_deref(), Some("their"));
+        assert_eq!(
+            locale.format("pronoun", &[], "en").as_deref(),
+            Some("their")
+        );
+    }
+
+    #[test]
+    fn selects_plural_variants() {
+        let locale = Locale::parse(SOURCE).unwrap();
+        let format = |count: f64, language| {
+            locale.format("emails", &args([("count", count.into())]), language)
+        };
+        assert_eq!(format(0., "en").as_deref(), Some("No emails"));
+        assert_eq!(format(1., "en").as_deref(), Some("One email"));
+        assert_eq!(format(2., "en").as_deref(), Some("2 emails"));
+        assert_eq!(format(1., "ja").as_deref(), Some("1 emails"));
+
+        assert_eq!(plural_category("ru", 21.), "one");
+        assert_eq!(plural_category("ru", 3.), "few");
+        assert_eq!(plural_category("ru", 12.), "many");
+        assert_eq!(plural_category("pl-PL", 22.), "few");
+        assert_eq!(plural_category("fr", 1.5), "one");
+        assert_eq!(plural_category("ar", 0.), "zero");
+        assert_eq!(plural_category("en", 1.5), "other");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = |source| Locale::parse(source).unwrap_err();
+
+        let missing_equals = error("greeting = hi\nfarewell");
+        assert_eq!(missing_equals.line, 2);
+        assert!(missing_equals.message.contains("key = value"));
+
+        assert!(error("bad key = value").message.contains("invalid key"));
+        assert!(error(" = value").message.contains("invalid key"));
+        assert!(error("greeting = { $name").message.contains("unclosed"));
+        assert!(error("greeting = { name }")
+            .message
+            .contains("unsupported placeable"));
+
+        let no_default = error("count = { $n ->\n    [one] one\n    [other] many\n    }");
+        assert_eq!(no_default.line, 1);
+        assert!(no_default.message.contains("no default variant"));
+    }
+}
@@ -8,6 +8,15 @@ pub trait BgColor: StyleComponentApplier<BackgroundColor> + Sized {
             v.0 = color;
         })
     }
+
+    fn desaturate(self, amount: f32, opacity: f32) -> Self {
+        self.get_component(move |v| {
+            let [r, g, b, a] = v.0.as_rgba_f32();
+            let grey = 0.299 * r + 0.587 * g + 0.114 * b;
+            let mix = |channel: f32| channel + (grey - channel) * amount;
+            v.0 = Color::rgba(mix(r), mix(g), mix(b), a * opacity);
+        })
+    }
 }
 
 impl<T: StyleComponentApplier<BackgroundColor> + Sized> BgColor for T {}
@@ -0,0 +1,193 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    DynStyler, InternalUiSpawner, NodeComponentsMut, StyleLayer, StyleLayers, Styler, Theme,
+    UiBundleGenerator, UiBundleGeneratorStyler, UiComponent, UiStyleSet,
+};
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UiClasses(Vec<String>);
+
+impl UiClasses {
+    pub fn new<C: Into<String>>(classes: impl IntoIterator<Item = C>) -> Self {
+        let mut result = Self::default();
+        for class in classes {
+            result.add_class(class);
+        }
+        result
+    }
+
+    pub fn contains(&self, class: &str) -> bool {
+        self.0.iter().any(|existing| existing == class)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    pub fn add_class(&mut self, class: impl Into<String>) {
+        let class = class.into();
+        if !self.contains(&class) {
+            self.0.push(class);
+        }
+    }
+
+    pub fn remove_class(&mut self, class: &str) {
+        self.0.retain(|existing| existing != class);
+    }
+
+    pub fn toggle_class(&mut self, class: impl Into<String>) -> bool {
+        let class = class.into();
+        if self.contains(&class) {
+            self.remove_class(&class);
+            false
+        } else {
+            self.0.push(class);
+            true
+        }
+    }
+
+    pub fn set_class(&mut self, class: impl Into<String>, enabled: bool) {
+        if enabled {
+            self.add_class(class);
+        } else {
+            self.remove_class(&class.into());
+        }
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn class(self, class: impl Into<String>) -> Self {
+        self.classes([class.into()])
+    }
+
+    pub fn classes<C: Into<String>>(self, classes: impl IntoIterator<Item = C>) -> Self {
+        let classes: Vec<String> = classes.into_iter().map(Into::into).collect();
+        self.with_inserter(move |commands| {
+            commands.add(move |entity: Entity, world: &mut World| {
+                let mut entity = world.entity_mut(entity);
+                match entity.get_mut::<UiClasses>() {
+                    Some(mut existing) => {
+                        for class in classes {
+                            existing.add_class(class);
+                        }
+                    }
+                    None => {
+                        entity.insert(UiClasses::new(classes));
+                    }
+                }
+            });
+        })
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct ClassStylers {
+    stylers: HashMap<String, Arc<dyn DynStyler>>,
+}
+
+impl ClassStylers {
+    pub fn register<S: Styler>(&mut self, class: impl Into<String>, styler: S) -> &mut Self {
+        self.stylers.insert(class.into(), Arc::new(styler));
+        self
+    }
+
+    pub fn get(&self, class: &str) -> Option<&Arc<dyn DynStyler>> {
+        self.stylers.get(class)
+    }
+
+    pub(crate) fn restyle<'a>(
+        &self,
+        classes: &UiClasses,
+        mut node: NodeComponentsMut<'a>,
+        mut text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>) {
+        for class in classes.iter() {
+            if let Some(styler) = self.get(class) {
+                (node, text) = styler.restyle(node, text);
+            }
+        }
+        (node, text)
+    }
+}
+
+pub trait ClassStylerAppExt {
+    fn register_class_styler<S: Styler>(
+        &mut self,
+        class: impl Into<String>,
+        styler: S,
+    ) -> &mut Self;
+}
+
+impl ClassStylerAppExt for App {
+    fn register_class_styler<S: Styler>(
+        &mut self,
+        class: impl Into<String>,
+        styler: S,
+    ) -> &mut Self {
+        self.init_resource::<ClassStylers>();
+        self.world
+            .resource_mut::<ClassStylers>()
+            .register(class, styler);
+        self
+    }
+}
+
+fn retheme_class_stylers(theme: Res<Theme>, mut class_stylers: ResMut<ClassStylers>) {
+    if !theme.is_changed() || theme.is_added() {
+        return;
+    }
+    for styler in class_stylers.stylers.values_mut() {
+        if let Some(rethemed) = styler.rethemed(&theme) {
+            *styler = rethemed;
+        }
+    }
+}
+
+fn invalidate_class_styles(
+    class_stylers: Res<ClassStylers>,
+    mut removed: RemovedComponents<UiClasses>,
+    mut nodes: Query<(Option<Ref<UiClasses>>, &mut StyleLayers)>,
+) {
+    let restyle_all = class_stylers.is_changed();
+    for (classes, mut layers) in nodes.iter_mut() {
+        if classes.is_some_and(|classes| restyle_all || classes.is_changed()) {
+            layers.invalidate(StyleLayer::Classes);
+        }
+    }
+    for entity in removed.iter() {
+        if let Ok((_, mut layers)) = nodes.get_mut(entity) {
+            layers.invalidate(StyleLayer::Classes);
+        }
+    }
+}
+
+pub struct ClassesPlugin;
+
+impl Plugin for ClassesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<UiClasses>()
+            .init_resource::<ClassStylers>()
+            .add_systems(
+                Update,
+                (
+                    retheme_class_stylers.in_set(UiStyleSet::Theme),
+                    invalidate_class_styles.in_set(UiStyleSet::Classes),
+                ),
+            );
+    }
+}
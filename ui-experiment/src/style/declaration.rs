@@ -0,0 +1,382 @@
+use bevy::prelude::*;
+
+use crate::{
+    BgColor, FontSelections, ImageApplier, Layout, LengthField, TextApplier, UiVal, UiValRect,
+    VisibilityApplier, ZIndexApplier,
+};
+
+#[derive(Clone, Debug)]
+pub enum StyleDeclaration {
+    Display(Display),
+    PositionType(PositionType),
+    Length(LengthField, UiVal),
+    FlexDirection(FlexDirection),
+    FlexWrap(FlexWrap),
+    AlignItems(AlignItems),
+    AlignSelf(AlignSelf),
+    AlignContent(AlignContent),
+    JustifyContent(JustifyContent),
+    JustifyItems(JustifyItems),
+    JustifySelf(JustifySelf),
+    FlexGrow(f32),
+    FlexShrink(f32),
+    Overflow(Overflow),
+    AspectRatio(Option<f32>),
+    BackgroundColor(Color),
+    Visibility(Visibility),
+    ZIndex(ZIndex),
+    TextColor(Color),
+    FontSize(f32),
+    Font(Handle<Font>),
+    FontFamily(String),
+    TextAlignment(TextAlignment),
+    Image(Handle<Image>),
+    Flip(bool, bool),
+}
+
+impl StyleDeclaration {
+    pub fn apply_node<T: Layout + BgColor + VisibilityApplier + ZIndexApplier>(
+        &self,
+        node: T,
+    ) -> T {
+        match self {
+            StyleDeclaration::Display(val) => node.display(*val),
+            StyleDeclaration::PositionType(val) => node.position_type(*val),
+            StyleDeclaration::Length(field, val) => node.length(*field, *val),
+            StyleDeclaration::FlexDirection(val) => node.flex_direction(*val),
+            StyleDeclaration::FlexWrap(val) => node.flex_wrap(*val),
+            StyleDeclaration::AlignItems(val) => node.align_items(*val),
+            StyleDeclaration::AlignSelf(val) => node.align_self(*val),
+            StyleDeclaration::AlignContent(val) => node.align_content(*val),
+            StyleDeclaration::JustifyContent(val) => node.justify_content(*val),
+            StyleDeclaration::JustifyItems(val) => node.justify_items(*val),
+            StyleDeclaration::JustifySelf(val) => node.justify_self(*val),
+            StyleDeclaration::FlexGrow(val) => node.flex_grow(*val),
+            StyleDeclaration::FlexShrink(val) => node.flex_shrink(*val),
+            StyleDeclaration::Overflow(val) => node.overflow(*val),
+            StyleDeclaration::AspectRatio(val) => node.aspect_ratio(*val),
+            StyleDeclaration::BackgroundColor(val) => node.bg(*val),
+            StyleDeclaration::Visibility(val) => VisibilityApplier::z_index(node, *val),
+            StyleDeclaration::ZIndex(val) => ZIndexApplier::z_index(node, *val),
+            _ => node,
+        }
+    }
+
+    pub fn apply_text<T: TextApplier>(&self, text: T) -> T {
+        match self {
+            StyleDeclaration::TextColor(val) => text.text_color(*val),
+            StyleDeclaration::FontSize(val) => text.font_size(*val),
+            StyleDeclaration::Font(val) => text.font(val.clone()),
+            StyleDeclaration::TextAlignment(val) => text.text_alignment(*val),
+            _ => text,
+        }
+    }
+
+    pub fn apply_fonts(&self, fonts: &mut FontSelections, section: usize) {
+        if let StyleDeclaration::FontFamily(name) = self {
+            fonts.section_mut(section).family = Some(name.clone());
+        }
+    }
+
+    pub fn apply_image<T: ImageApplier>(&self, image: T) -> T {
+        match self {
+            StyleDeclaration::Image(val) => image.texture(val.clone()),
+            StyleDeclaration::Flip(x, y) => image.flip(*x, *y),
+            _ => image,
+        }
+    }
+
+    pub fn parse(
+        property: &str,
+        value: &str,
+        load_font: &mut dyn FnMut(&str) -> Handle<Font>,
+        load_image: &mut dyn FnMut(&str) -> Handle<Image>,
+    ) -> Result<Vec<StyleDeclaration>, String> {
+        let value = value.trim();
+        let single =
+            |declaration| -> Result<Vec<StyleDeclaration>, String> { Ok(vec![declaration]) };
+        let length = |field| -> Result<Vec<StyleDeclaration>, String> {
+            single(StyleDeclaration::Length(field, parse_val(value)?))
+        };
+        let rect = |[left, right, top, bottom]: [LengthField; 4]| -> Result<Vec<_>, String> {
+            let rect = parse_rect(value)?;
+            Ok(vec![
+                StyleDeclaration::Length(left, rect.left),
+                StyleDeclaration::Length(right, rect.right),
+                StyleDeclaration::Length(top, rect.top),
+                StyleDeclaration::Length(bottom, rect.bottom),
+            ])
+        };
+
+        match property.trim() {
+            "display" => single(StyleDeclaration::Display(match value {
+                "flex" => Display::Flex,
+                "grid" => Display::Grid,
+                "none" => Display::None,
+                _ => return Err(invalid(property, value)),
+            })),
+            "position" => single(StyleDeclaration::PositionType(match value {
+                "relative" => PositionType::Relative,
+                "absolute" => PositionType::Absolute,
+                _ => return Err(invalid(property, value)),
+            })),
+            "left" => length(LengthField::Left),
+            "right" => length(LengthField::Right),
+            "top" => length(LengthField::Top),
+            "bottom" => length(LengthField::Bottom),
+            "width" => length(LengthField::Width),
+            "height" => length(LengthField::Height),
+            "min-width" => length(LengthField::MinWidth),
+            "min-height" => length(LengthField::MinHeight),
+            "max-width" => length(LengthField::MaxWidth),
+            "max-height" => length(LengthField::MaxHeight),
+            "flex-basis" => length(LengthField::FlexBasis),
+            "row-gap" => length(LengthField::RowGap),
+            "column-gap" => length(LengthField::ColumnGap),
+            "gap" => {
+                let val = parse_val(value)?;
+                Ok(vec![
+                    StyleDeclaration::Length(LengthField::RowGap, val),
+                    StyleDeclaration::Length(LengthField::ColumnGap, val),
+                ])
+            }
+            "inset" => rect([
+                LengthField::Left,
+                LengthField::Right,
+                LengthField::Top,
+                LengthField::Bottom,
+            ]),
+            "margin" => rect(LengthField::MARGIN),
+            "padding" => rect(LengthField::PADDING),
+            "border" | "border-width" => rect(LengthField::BORDER),
+            "flex-direction" => single(StyleDeclaration::FlexDirection(match value {
+                "row" => FlexDirection::Row,
+                "column" => FlexDirection::Column,
+                "row-reverse" => FlexDirection::RowReverse,
+                "column-reverse" => FlexDirection::ColumnReverse,
+                _ => return Err(invalid(property, value)),
+            })),
+            "flex-wrap" => single(StyleDeclaration::FlexWrap(match value {
+                "nowrap" => FlexWrap::NoWrap,
+                "wrap" => FlexWrap::Wrap,
+                "wrap-reverse" => FlexWrap::WrapReverse,
+                _ => return Err(invalid(property, value)),
+            })),
+            "align-items" => single(StyleDeclaration::AlignItems(match value {
+                "default" => AlignItems::Default,
+                "start" => AlignItems::Start,
+                "end" => AlignItems::End,
+                "flex-start" => AlignItems::FlexStart,
+                "flex-end" => AlignItems::FlexEnd,
+                "center" => AlignItems::Center,
+                "baseline" => AlignItems::Baseline,
+                "stretch" => AlignItems::Stretch,
+                _ => return Err(invalid(property, value)),
+            })),
+            "align-self" => single(StyleDeclaration::AlignSelf(match value {
+                "auto" => AlignSelf::Auto,
+                "start" => AlignSelf::Start,
+                "end" => AlignSelf::End,
+                "flex-start" => AlignSelf::FlexStart,
+                "flex-end" => AlignSelf::FlexEnd,
+                "center" => AlignSelf::Center,
+                "baseline" => AlignSelf::Baseline,
+                "stretch" => AlignSelf::Stretch,
+                _ => return Err(invalid(property, value)),
+            })),
+            "align-content" => single(StyleDeclaration::AlignContent(match value {
+                "default" => AlignContent::Default,
+                "start" => AlignContent::Start,
+                "end" => AlignContent::End,
+                "flex-start" => AlignContent::FlexStart,
+                "flex-end" => AlignContent::FlexEnd,
+                "center" => AlignContent::Center,
+                "stretch" => AlignContent::Stretch,
+                "space-between" => AlignContent::SpaceBetween,
+                "space-evenly" => AlignContent::SpaceEvenly,
+                "space-around" => AlignContent::SpaceAround,
+                _ => return Err(invalid(property, value)),
+            })),
+            "justify-content" => single(StyleDeclaration::JustifyContent(match value {
+                "default" => JustifyContent::Default,
+                "start" => JustifyContent::Start,
+                "end" => JustifyContent::End,
+                "flex-start" => JustifyContent::FlexStart,
+                "flex-end" => JustifyContent::FlexEnd,
+                "center" => JustifyContent::Center,
+                "space-between" => JustifyContent::SpaceBetween,
+                "space-evenly" => JustifyContent::SpaceEvenly,
+                "space-around" => JustifyContent::SpaceAround,
+                _ => return Err(invalid(property, value)),
+            })),
+            "justify-items" => single(StyleDeclaration::JustifyItems(match value {
+                "default" => JustifyItems::Default,
+                "start" => JustifyItems::Start,
+                "end" => JustifyItems::End,
+                "center" => JustifyItems::Center,
+                "baseline" => JustifyItems::Baseline,
+                "stretch" => JustifyItems::Stretch,
+                _ => return Err(invalid(property, value)),
+            })),
+            "justify-self" => single(StyleDeclaration::JustifySelf(match value {
+                "auto" => JustifySelf::Auto,
+                "start" => JustifySelf::Start,
+                "end" => JustifySelf::End,
+                "center" => JustifySelf::Center,
+                "baseline" => JustifySelf::Baseline,
+                "stretch" => JustifySelf::Stretch,
+                _ => return Err(invalid(property, value)),
+            })),
+            "flex-grow" => single(StyleDeclaration::FlexGrow(parse_number(value)?)),
+            "flex-shrink" => single(StyleDeclaration::FlexShrink(parse_number(value)?)),
+            "overflow" => single(StyleDeclaration::Overflow(match value {
+                "visible" => Overflow::visible(),
+                "clip" => Overflow::clip(),
+                "clip-x" => Overflow::clip_x(),
+                "clip-y" => Overflow::clip_y(),
+                _ => return Err(invalid(property, value)),
+            })),
+            "aspect-ratio" => single(StyleDeclaration::AspectRatio(match value {
+                "auto" => None,
+                _ => Some(parse_number(value)?),
+            })),
+            "background" | "background-color" => {
+                single(StyleDeclaration::BackgroundColor(parse_color(value)?))
+            }
+            "visibility" => single(StyleDeclaration::Visibility(match value {
+                "visible" => Visibility::Visible,
+                "hidden" => Visibility::Hidden,
+                "inherited" => Visibility::Inherited,
+                _ => return Err(invalid(property, value)),
+            })),
+            "z-index" => single(StyleDeclaration::ZIndex(
+                match value
+                    .strip_prefix("global(")
+                    .and_then(|value| value.strip_suffix(')'))
+                {
+                    Some(global) => ZIndex::Global(parse_integer(global)?),
+                    None => ZIndex::Local(parse_integer(value)?),
+                },
+            )),
+            "color" => single(StyleDeclaration::TextColor(parse_color(value)?)),
+            "font-size" => single(StyleDeclaration::FontSize(parse_number(
+                value.strip_suffix("px").unwrap_or(value),
+            )?)),
+            "font" => single(StyleDeclaration::Font(load_font(unquote(value)))),
+            "font-family" => single(StyleDeclaration::FontFamily(unquote(value).to_string())),
+            "text-align" => single(StyleDeclaration::TextAlignment(match value {
+                "left" => TextAlignment::Left,
+                "center" => TextAlignment::Center,
+                "right" => TextAlignment::Right,
+                _ => return Err(invalid(property, value)),
+            })),
+            "image" => single(StyleDeclaration::Image(load_image(unquote(value)))),
+            "flip" => single(match value {
+                "none" => StyleDeclaration::Flip(false, false),
+                "x" => StyleDeclaration::Flip(true, false),
+                "y" => StyleDeclaration::Flip(false, true),
+                "both" => StyleDeclaration::Flip(true, true),
+                _ => return Err(invalid(property, value)),
+            }),
+            property => Err(format!("unknown property `{property}`")),
+        }
+    }
+}
+
+fn invalid(property: &str, value: &str) -> String {
+    format!("invalid value `{value}` for `{property}`")
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim_matches(|c| c == '"' || c == '\'')
+}
+
+pub fn parse_number(value: &str) -> Result<f32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid number `{value}`"))
+}
+
+fn parse_integer(value: &str) -> Result<i32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid integer `{value}`"))
+}
+
+pub fn parse_val(value: &str) -> Result<UiVal, String> {
+    let value = value.trim();
+    if value == "auto" {
+        return Ok(UiVal::Auto);
+    }
+    let units: [(&str, fn(f32) -> UiVal); 9] = [
+        ("px", UiVal::Px),
+        ("%", UiVal::Percent),
+        ("rem", UiVal::Rem),
+        ("em", UiVal::Em),
+        ("vw", UiVal::Vw),
+        ("vh", UiVal::Vh),
+        ("vmin", UiVal::VMin),
+        ("vmax", UiVal::VMax),
+        ("", UiVal::Px),
+    ];
+    units
+        .iter()
+        .find_map(|(suffix, unit)| {
+            value
+                .strip_suffix(suffix)
+                .and_then(|number| number.parse().ok())
+                .map(unit)
+        })
+        .ok_or_else(|| format!("invalid length `{value}`"))
+}
+
+pub fn parse_rect(value: &str) -> Result<UiValRect, String> {
+    let values = value
+        .split_whitespace()
+        .map(parse_val)
+        .collect::<Result<Vec<_>, _>>()?;
+    match values[..] {
+        [all] => Ok(UiValRect::all(all)),
+        [vertical, horizontal] => Ok(UiValRect::axes(horizontal, vertical)),
+        [top, horizontal, bottom] => Ok(UiValRect::new(horizontal, horizontal, top, bottom)),
+        [top, right, bottom, left] => Ok(UiValRect::new(left, right, top, bottom)),
+        _ => Err(format!("invalid rect `{value}`")),
+    }
+}
+
+pub fn parse_color(value: &str) -> Result<Color, String> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return Color::hex(hex).map_err(|_| format!("invalid color `{value}`"));
+    }
+    if let Some(args) = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|args| args.strip_suffix(')'))
+    {
+        let args = args
+            .split(',')
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+        return match args[..] {
+            [r, g, b] => Ok(Color::rgb_u8(r as u8, g as u8, b as u8)),
+            [r, g, b, a] => Ok(Color::rgba_u8(r as u8, g as u8, b as u8, (a * 255.) as u8)),
+            _ => Err(format!("invalid color `{value}`")),
+        };
+    }
+    match value {
+        "none" | "transparent" => Ok(Color::NONE),
+        "white" => Ok(Color::WHITE),
+        "black" => Ok(Color::BLACK),
+        "gray" | "grey" => Ok(Color::GRAY),
+        "red" => Ok(Color::RED),
+        "green" => Ok(Color::GREEN),
+        "blue" => Ok(Color::BLUE),
+        "yellow" => Ok(Color::YELLOW),
+        "orange" => Ok(Color::ORANGE),
+        "purple" => Ok(Color::PURPLE),
+        _ => Err(format!("invalid color `{value}`")),
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{NodeComponentsMut, Styler, TextApplier, Theme};
+
+pub trait DynStyler: Send + Sync + 'static {
+    fn restyle<'a>(
+        &self,
+        node: NodeComponentsMut<'a>,
+        text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>);
+    fn restyle_disabled<'a>(&self, node: NodeComponentsMut<'a>) -> NodeComponentsMut<'a>;
+    fn restyle_sections(&self, text: &mut Text, from: usize);
+    fn section_style(&self, style: TextStyle) -> TextStyle;
+    fn rethemed(&self, theme: &Theme) -> Option<Arc<dyn DynStyler>>;
+}
+
+impl<S: Styler> DynStyler for S {
+    fn restyle<'a>(
+        &self,
+        node: NodeComponentsMut<'a>,
+        text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>) {
+        let text = text.map(|text| self.text_style(text).style_sections(self, 0));
+        (self.style(node), text)
+    }
+
+    fn restyle_disabled<'a>(&self, node: NodeComponentsMut<'a>) -> NodeComponentsMut<'a> {
+        self.disabled_style(node)
+    }
+
+    fn restyle_sections(&self, text: &mut Text, from: usize) {
+        for section in text.sections.iter_mut().skip(from) {
+            section.style = self.text_section_style(std::mem::take(&mut section.style));
+        }
+    }
+
+    fn section_style(&self, style: TextStyle) -> TextStyle {
+        self.text_section_style(style)
+    }
+
+    fn rethemed(&self, theme: &Theme) -> Option<Arc<dyn DynStyler>> {
+        self.with_theme(theme)
+            .map(|styler| Arc::new(styler) as Arc<dyn DynStyler>)
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct StyledBy(pub Arc<dyn DynStyler>);
+
+impl StyledBy {
+    pub fn new<S: Styler>(styler: Arc<S>) -> Self {
+        Self(styler)
+    }
+}
@@ -0,0 +1,206 @@
+use bevy::{prelude::*, ui::UiSystem, utils::HashMap};
+
+use crate::{apply_rich_text, style_appended_sections};
+
+pub const DEFAULT_FONT_FAMILY: &str = "default";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FontVariant {
+    pub bold: bool,
+    pub italic: bool,
+}
+
+impl FontVariant {
+    pub const REGULAR: FontVariant = FontVariant {
+        bold: false,
+        italic: false,
+    };
+    pub const BOLD: FontVariant = FontVariant {
+        bold: true,
+        italic: false,
+    };
+    pub const ITALIC: FontVariant = FontVariant {
+        bold: false,
+        italic: true,
+    };
+    pub const BOLD_ITALIC: FontVariant = FontVariant {
+        bold: true,
+        italic: true,
+    };
+
+    pub fn bolded(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    pub fn italicized(self) -> Self {
+        Self {
+            italic: true,
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FontFamily {
+    pub regular: Handle<Font>,
+    pub bold: Option<Handle<Font>>,
+    pub italic: Option<Handle<Font>>,
+    pub bold_italic: Option<Handle<Font>>,
+}
+
+impl FontFamily {
+    pub fn new(regular: Handle<Font>) -> Self {
+        Self {
+            regular,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_bold(mut self, bold: Handle<Font>) -> Self {
+        self.bold = Some(bold);
+        self
+    }
+
+    pub fn with_italic(mut self, italic: Handle<Font>) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+
+    pub fn with_bold_italic(mut self, bold_italic: Handle<Font>) -> Self {
+        self.bold_italic = Some(bold_italic);
+        self
+    }
+
+    pub fn variant(&self, variant: FontVariant) -> Handle<Font> {
+        let candidates = match (variant.bold, variant.italic) {
+            (false, false) => [None, None, None],
+            (true, false) => [self.bold.as_ref(), None, None],
+            (false, true) => [self.italic.as_ref(), None, None],
+            (true, true) => [
+                self.bold_italic.as_ref(),
+                self.bold.as_ref(),
+                self.italic.as_ref(),
+            ],
+        };
+        candidates
+            .into_iter()
+            .flatten()
+            .next()
+            .unwrap_or(&self.regular)
+            .clone()
+    }
+
+    fn contains(&self, font: &Handle<Font>) -> bool {
+        self.regular == *font
+            || [&self.bold, &self.italic, &self.bold_italic]
+                .into_iter()
+                .flatten()
+                .any(|variant| variant == font)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontSelection {
+    pub family: Option<String>,
+    pub variant: FontVariant,
+}
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontSelections(Vec<Option<FontSelection>>);
+
+impl FontSelections {
+    pub fn get(&self, section: usize) -> Option<&FontSelection> {
+        self.0.get(section).and_then(Option::as_ref)
+    }
+
+    pub fn section_mut(&mut self, section: usize) -> &mut FontSelection {
+        if self.0.len() <= section {
+            self.0.resize(section + 1, None);
+        }
+        self.0[section].get_or_insert_with(FontSelection::default)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct FontFamilies {
+    families: HashMap<String, FontFamily>,
+}
+
+impl FontFamilies {
+    pub fn with_family(mut self, name: impl Into<String>, family: FontFamily) -> Self {
+        self.insert(name, family);
+        self
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, family: FontFamily) {
+        self.families.insert(name.into(), family);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FontFamily> {
+        self.families.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.families.keys().map(String::as_str)
+    }
+
+    pub fn family_of(&self, font: &Handle<Font>) -> Option<&FontFamily> {
+        self.families
+            .values()
+            .find(|family| family.contains(font))
+            .or_else(|| {
+                (*font == Handle::default())
+                    .then(|| self.get(DEFAULT_FONT_FAMILY))
+                    .flatten()
+            })
+    }
+
+    pub fn resolve(&self, font: &Handle<Font>, selection: &FontSelection) -> Option<Handle<Font>> {
+        let family = match &selection.family {
+            Some(name) => self.get(name),
+            None => self.family_of(font),
+        };
+        family.map(|family| family.variant(selection.variant))
+    }
+}
+
+fn resolve_font_families(
+    families: Res<FontFamilies>,
+    mut texts: Query<(Ref<FontSelections>, &mut Text)>,
+) {
+    for (selections, mut text) in texts.iter_mut() {
+        if !families.is_changed() && !selections.is_changed() && !text.is_changed() {
+            continue;
+        }
+        let resolved: Vec<(usize, Handle<Font>)> = text
+            .sections
+            .iter()
+            .enumerate()
+            .filter_map(|(index, section)| {
+                let font = families.resolve(&section.style.font, selections.get(index)?)?;
+                (font != section.style.font).then_some((index, font))
+            })
+            .collect();
+        for (index, font) in resolved {
+            text.sections[index].style.font = font;
+        }
+    }
+}
+
+pub struct FontFamilyPlugin;
+
+impl Plugin for FontFamilyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FontFamilies>().add_systems(
+            PostUpdate,
+            resolve_font_families
+                .after(apply_rich_text)
+                .after(style_appended_sections)
+                .before(UiSystem::Layout),
+        );
+    }
+}
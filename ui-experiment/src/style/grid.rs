@@ -0,0 +1,83 @@
+use bevy::ui::{
+    GridTrack, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    RepeatedGridTrack,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackBreadth {
+    Px(f32),
+    Percent(f32),
+    Fr(f32),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl From<TrackBreadth> for MinTrackSizingFunction {
+    fn from(value: TrackBreadth) -> Self {
+        match value {
+            TrackBreadth::Px(value) => MinTrackSizingFunction::Px(value),
+            TrackBreadth::Percent(value) => MinTrackSizingFunction::Percent(value),
+            TrackBreadth::Fr(_) | TrackBreadth::Auto => MinTrackSizingFunction::Auto,
+            TrackBreadth::MinContent => MinTrackSizingFunction::MinContent,
+            TrackBreadth::MaxContent => MinTrackSizingFunction::MaxContent,
+        }
+    }
+}
+
+impl From<TrackBreadth> for MaxTrackSizingFunction {
+    fn from(value: TrackBreadth) -> Self {
+        match value {
+            TrackBreadth::Px(value) => MaxTrackSizingFunction::Px(value),
+            TrackBreadth::Percent(value) => MaxTrackSizingFunction::Percent(value),
+            TrackBreadth::Fr(value) => MaxTrackSizingFunction::Fraction(value),
+            TrackBreadth::Auto => MaxTrackSizingFunction::Auto,
+            TrackBreadth::MinContent => MaxTrackSizingFunction::MinContent,
+            TrackBreadth::MaxContent => MaxTrackSizingFunction::MaxContent,
+        }
+    }
+}
+
+pub fn px(value: f32) -> GridTrack {
+    GridTrack::px(value)
+}
+
+pub fn percent(value: f32) -> GridTrack {
+    GridTrack::percent(value)
+}
+
+pub fn fr(value: f32) -> GridTrack {
+    GridTrack::fr(value)
+}
+
+pub fn auto() -> GridTrack {
+    GridTrack::auto()
+}
+
+pub fn min_content() -> GridTrack {
+    GridTrack::min_content()
+}
+
+pub fn max_content() -> GridTrack {
+    GridTrack::max_content()
+}
+
+pub fn fit_content(value: f32) -> GridTrack {
+    GridTrack::fit_content_px(value)
+}
+
+pub fn minmax(min: TrackBreadth, max: TrackBreadth) -> GridTrack {
+    GridTrack::minmax(min.into(), max.into())
+}
+
+pub fn repeat(count: u16, tracks: impl Into<Vec<GridTrack>>) -> RepeatedGridTrack {
+    RepeatedGridTrack::repeat_many(count, tracks)
+}
+
+pub fn auto_fill(tracks: impl Into<Vec<GridTrack>>) -> RepeatedGridTrack {
+    RepeatedGridTrack::repeat_many(GridTrackRepetition::AutoFill, tracks)
+}
+
+pub fn auto_fit(tracks: impl Into<Vec<GridTrack>>) -> RepeatedGridTrack {
+    RepeatedGridTrack::repeat_many(GridTrackRepetition::AutoFit, tracks)
+}
@@ -0,0 +1,596 @@
+use bevy::{ecs::query::Has, prelude::*};
+
+use crate::{
+    parse_utilities, ClassStylers, FontSelections, LengthField, MatchedRules, NodeComponentsMut,
+    StateStyles, StyledBy, Theme, UiClasses, UiLengths, UiState, UtilityClasses, ViewportStylers,
+};
+
+macro_rules! style_fields {
+    ($($field:ident),* $(,)?) => {
+        const STYLE_FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+        fn style_field_eq(field: &str, a: &Style, b: &Style) -> bool {
+            match field {
+                $(stringify!($field) => a.$field == b.$field,)*
+                _ => true,
+            }
+        }
+
+        fn copy_style_field(field: &str, from: &Style, to: &mut Style) {
+            match field {
+                $(stringify!($field) => to.$field.clone_from(&from.$field),)*
+                _ => {}
+            }
+        }
+    };
+}
+
+style_fields!(
+    display,
+    position_type,
+    overflow,
+    direction,
+    left,
+    right,
+    top,
+    bottom,
+    width,
+    height,
+    min_width,
+    min_height,
+    max_width,
+    max_height,
+    aspect_ratio,
+    align_items,
+    justify_items,
+    align_self,
+    justify_self,
+    align_content,
+    justify_content,
+    margin,
+    padding,
+    border,
+    flex_direction,
+    flex_wrap,
+    flex_grow,
+    flex_shrink,
+    flex_basis,
+    row_gap,
+    column_gap,
+    grid_auto_flow,
+    grid_template_rows,
+    grid_template_columns,
+    grid_auto_rows,
+    grid_auto_columns,
+    grid_row,
+    grid_column,
+);
+
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UiStyleSet {
+    Theme,
+    Stylesheet,
+    Classes,
+    Utilities,
+    Viewport,
+    State,
+    Apply,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StyleLayer {
+    Base,
+    Stylesheet,
+    Classes,
+    Utilities,
+    Viewport,
+    Inline,
+    State,
+}
+
+impl StyleLayer {
+    const RECORDED: [StyleLayer; 6] = [
+        StyleLayer::Stylesheet,
+        StyleLayer::Classes,
+        StyleLayer::Utilities,
+        StyleLayer::Viewport,
+        StyleLayer::Inline,
+        StyleLayer::State,
+    ];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StyleKey {
+    Style(&'static str),
+    Length(LengthField),
+    BackgroundColor,
+    FocusPolicy,
+    ZIndex,
+    Visibility,
+    Section(usize),
+    Alignment,
+    Image,
+    Fonts,
+}
+
+fn same_z_index(a: ZIndex, b: ZIndex) -> bool {
+    matches!(
+        (a, b),
+        (ZIndex::Local(a), ZIndex::Local(b)) | (ZIndex::Global(a), ZIndex::Global(b)) if a == b
+    )
+}
+
+fn same_text_style(a: &TextStyle, b: &TextStyle) -> bool {
+    a.font == b.font && a.font_size == b.font_size && a.color == b.color
+}
+
+fn same_image(a: &UiImage, b: &UiImage) -> bool {
+    a.texture == b.texture && a.flip_x == b.flip_x && a.flip_y == b.flip_y
+}
+
+type NodeRefs<'a> = (
+    &'a Style,
+    &'a BackgroundColor,
+    &'a FocusPolicy,
+    &'a ZIndex,
+    &'a Visibility,
+    &'a UiLengths,
+);
+
+#[derive(Clone)]
+pub(crate) struct StyleSnapshot {
+    style: Style,
+    background_color: Color,
+    focus_policy: FocusPolicy,
+    z_index: ZIndex,
+    visibility: Visibility,
+    lengths: UiLengths,
+    sections: Vec<TextStyle>,
+    alignment: Option<TextAlignment>,
+    image: Option<UiImage>,
+    fonts: Option<FontSelections>,
+}
+
+impl StyleSnapshot {
+    fn new(
+        (style, background_color, focus_policy, z_index, visibility, lengths): NodeRefs,
+        text: Option<&Text>,
+        image: Option<&UiImage>,
+        fonts: Option<&FontSelections>,
+    ) -> Self {
+        Self {
+            style: style.clone(),
+            background_color: background_color.0,
+            focus_policy: *focus_policy,
+            z_index: *z_index,
+            visibility: *visibility,
+            lengths: lengths.clone(),
+            sections: text
+                .map(|text| {
+                    text.sections
+                        .iter()
+                        .map(|section| section.style.clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            alignment: text.map(|text| text.alignment),
+            image: image.cloned(),
+            fonts: fonts.cloned(),
+        }
+    }
+
+    fn from_entity(entity: EntityRef) -> Option<Self> {
+        Some(Self::new(
+            (
+                entity.get()?,
+                entity.get()?,
+                entity.get()?,
+                entity.get()?,
+                entity.get()?,
+                entity.get()?,
+            ),
+            entity.get(),
+            entity.get(),
+            entity.get(),
+        ))
+    }
+
+    pub(crate) fn capture(
+        node: &NodeComponentsMut,
+        text: Option<&Text>,
+        image: Option<&UiImage>,
+        fonts: Option<&FontSelections>,
+    ) -> Self {
+        Self::new(
+            (&*node.0, &*node.1, &*node.2, &*node.3, &*node.4, &*node.5),
+            text,
+            image,
+            fonts,
+        )
+    }
+
+    fn diff(&self, after: &StyleSnapshot) -> Vec<StyleKey> {
+        let mut keys: Vec<StyleKey> = STYLE_FIELDS
+            .iter()
+            .copied()
+            .filter(|field| !style_field_eq(field, &self.style, &after.style))
+            .map(StyleKey::Style)
+            .collect();
+        for (field, _) in self.lengths.iter().chain(after.lengths.iter()) {
+            let key = StyleKey::Length(field);
+            if self.lengths.get(field) != after.lengths.get(field) && !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        if self.background_color != after.background_color {
+            keys.push(StyleKey::BackgroundColor);
+        }
+        if self.focus_policy != after.focus_policy {
+            keys.push(StyleKey::FocusPolicy);
+        }
+        if !same_z_index(self.z_index, after.z_index) {
+            keys.push(StyleKey::ZIndex);
+        }
+        if self.visibility != after.visibility {
+            keys.push(StyleKey::Visibility);
+        }
+        for (index, (before, after)) in self.sections.iter().zip(after.sections.iter()).enumerate()
+        {
+            if !same_text_style(before, after) {
+                keys.push(StyleKey::Section(index));
+            }
+        }
+        if self.alignment != after.alignment {
+            keys.push(StyleKey::Alignment);
+        }
+        if let (Some(before), Some(after)) = (&self.image, &after.image) {
+            if !same_image(before, after) {
+                keys.push(StyleKey::Image);
+            }
+        }
+        if self.fonts != after.fonts {
+            keys.push(StyleKey::Fonts);
+        }
+        keys
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct AppliedLayer {
+    layer: StyleLayer,
+    before: StyleSnapshot,
+    after: StyleSnapshot,
+    keys: Vec<StyleKey>,
+}
+
+impl AppliedLayer {
+    fn record(layer: StyleLayer, before: StyleSnapshot, after: StyleSnapshot) -> Option<Self> {
+        let keys = before.diff(&after);
+        (!keys.is_empty()).then_some(Self {
+            layer,
+            before,
+            after,
+            keys,
+        })
+    }
+
+    fn undo(
+        &self,
+        node: &mut NodeComponentsMut,
+        text: &mut Option<Mut<Text>>,
+        image: &mut Option<Mut<UiImage>>,
+        fonts: &mut Option<Mut<FontSelections>>,
+    ) {
+        self.write(&self.before, false, node, text, image, fonts);
+    }
+
+    fn redo(
+        &self,
+        node: &mut NodeComponentsMut,
+        text: &mut Option<Mut<Text>>,
+        image: &mut Option<Mut<UiImage>>,
+        fonts: &mut Option<Mut<FontSelections>>,
+    ) {
+        self.write(&self.after, true, node, text, image, fonts);
+    }
+
+    fn write(
+        &self,
+        from: &StyleSnapshot,
+        force: bool,
+        node: &mut NodeComponentsMut,
+        text: &mut Option<Mut<Text>>,
+        image: &mut Option<Mut<UiImage>>,
+        fonts: &mut Option<Mut<FontSelections>>,
+    ) {
+        let expected = &self.after;
+        for key in self.keys.iter() {
+            match *key {
+                StyleKey::Style(field) => {
+                    if force || style_field_eq(field, &node.0, &expected.style) {
+                        copy_style_field(field, &from.style, &mut node.0);
+                    }
+                }
+                StyleKey::Length(field) => {
+                    if force || node.5.get(field) == expected.lengths.get(field) {
+                        node.5.restore(field, from.lengths.get(field));
+                        *field.get_mut(&mut node.0) = field.get(&from.style);
+                    }
+                }
+                StyleKey::BackgroundColor => {
+                    if force || node.1 .0 == expected.background_color {
+                        node.1 .0 = from.background_color;
+                    }
+                }
+                StyleKey::FocusPolicy => {
+                    if force || *node.2 == expected.focus_policy {
+                        *node.2 = from.focus_policy;
+                    }
+                }
+                StyleKey::ZIndex => {
+                    if force || same_z_index(*node.3, expected.z_index) {
+                        *node.3 = from.z_index;
+                    }
+                }
+                StyleKey::Visibility => {
+                    if force || *node.4 == expected.visibility {
+                        *node.4 = from.visibility;
+                    }
+                }
+                StyleKey::Section(index) => {
+                    let Some(text) = text else {
+                        continue;
+                    };
+                    let (Some(style), Some(expected_style)) =
+                        (from.sections.get(index), expected.sections.get(index))
+                    else {
+                        continue;
+                    };
+                    if text.sections.get(index).is_some_and(|section| {
+                        force || same_text_style(&section.style, expected_style)
+                    }) {
+                        text.sections[index].style = style.clone();
+                    }
+                }
+                StyleKey::Alignment => {
+                    if let (Some(text), Some(alignment)) = (text.as_mut(), from.alignment) {
+                        if force || Some(text.alignment) == expected.alignment {
+                            text.alignment = alignment;
+                        }
+                    }
+                }
+                StyleKey::Image => {
+                    if let (Some(image), Some(from_image), Some(expected_image)) =
+                        (image.as_mut(), &from.image, &expected.image)
+                    {
+                        if force || same_image(image, expected_image) {
+                            **image = from_image.clone();
+                        }
+                    }
+                }
+                StyleKey::Fonts => {
+                    if let (Some(fonts), Some(from_fonts)) = (fonts.as_mut(), &from.fonts) {
+                        if force || Some(&**fonts) == expected.fonts.as_ref() {
+                            **fonts = from_fonts.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Component, Default)]
+pub struct StyleLayers {
+    applied: Vec<AppliedLayer>,
+    dirty: Option<StyleLayer>,
+    spawned: Option<StyleSnapshot>,
+    pub(crate) stylesheet: MatchedRules,
+    pub(crate) viewport: ViewportStylers,
+}
+
+impl StyleLayers {
+    pub fn invalidate(&mut self, layer: StyleLayer) {
+        self.dirty = Some(match self.dirty {
+            Some(dirty) => dirty.min(layer),
+            None => layer,
+        });
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.is_some()
+    }
+}
+
+pub(crate) fn capture_spawned_style(entity: Entity, world: &mut World) {
+    let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if !entity_mut.contains::<UiLengths>() {
+        entity_mut.insert(UiLengths::default());
+    }
+    let spawned = world
+        .get_entity(entity)
+        .and_then(StyleSnapshot::from_entity);
+    if let Some(mut layers) = world.get_mut::<StyleLayers>(entity) {
+        layers.spawned = spawned;
+    }
+}
+
+pub(crate) fn record_inline_style(entity: Entity, world: &mut World) {
+    let Some(after) = world
+        .get_entity(entity)
+        .and_then(StyleSnapshot::from_entity)
+    else {
+        return;
+    };
+    let Some(mut layers) = world.get_mut::<StyleLayers>(entity) else {
+        return;
+    };
+    if let Some(before) = layers.spawned.take() {
+        let inline = AppliedLayer::record(StyleLayer::Inline, before, after);
+        layers.applied.extend(inline);
+    }
+}
+
+type LayeredComponents<'a> = (
+    &'a mut Style,
+    &'a mut BackgroundColor,
+    &'a mut FocusPolicy,
+    &'a mut ZIndex,
+    &'a mut Visibility,
+    &'a mut UiLengths,
+);
+
+type LayeredNode<'a> = (
+    &'a mut StyleLayers,
+    Option<&'a StyledBy>,
+    LayeredComponents<'a>,
+    Option<&'a mut Text>,
+    Option<&'a mut UiImage>,
+    Option<&'a mut FontSelections>,
+    Option<&'a UiClasses>,
+    Option<&'a UtilityClasses>,
+    Option<(&'a UiState, &'a StateStyles)>,
+);
+
+fn apply_style_layers(
+    theme: Res<Theme>,
+    class_stylers: Res<ClassStylers>,
+    mut nodes: Query<LayeredNode, Changed<StyleLayers>>,
+) {
+    for (
+        mut layers,
+        styled_by,
+        mut node,
+        mut text,
+        mut image,
+        mut fonts,
+        classes,
+        utilities,
+        state,
+    ) in nodes.iter_mut()
+    {
+        let Some(level) = layers.dirty else {
+            continue;
+        };
+        let layers = &mut *layers;
+        layers.dirty = None;
+
+        let mut inline = None;
+        while layers
+            .applied
+            .last()
+            .is_some_and(|applied| applied.layer >= level)
+        {
+            let Some(applied) = layers.applied.pop() else {
+                break;
+            };
+            applied.undo(&mut node, &mut text, &mut image, &mut fonts);
+            if applied.layer == StyleLayer::Inline {
+                inline = Some(applied);
+            }
+        }
+
+        if level == StyleLayer::Base {
+            if let Some(styled_by) = styled_by {
+                (node, text) = styled_by.0.restyle(node, text);
+            }
+        }
+
+        for layer in StyleLayer::RECORDED
+            .into_iter()
+            .filter(|layer| *layer >= level)
+        {
+            let before =
+                StyleSnapshot::capture(&node, text.as_deref(), image.as_deref(), fonts.as_deref());
+            match layer {
+                StyleLayer::Stylesheet => {
+                    for declaration in layers.stylesheet.declarations.iter() {
+                        node = declaration.apply_node(node);
+                        if let (Some(fonts), Some(text)) = (fonts.as_mut(), text.as_ref()) {
+                            declaration.apply_fonts(fonts, text.sections.len().saturating_sub(1));
+                        }
+                        text = text.map(|text| declaration.apply_text(text));
+                        image = image.map(|image| declaration.apply_image(image));
+                    }
+                }
+                StyleLayer::Classes => {
+                    if let Some(classes) = classes {
+                        (node, text) = class_stylers.restyle(classes, node, text);
+                    }
+                }
+                StyleLayer::Utilities => {
+                    if let Some(utilities) = utilities {
+                        let (declarations, _) = parse_utilities(&utilities.0, &theme);
+                        for declaration in declarations.iter() {
+                            node = declaration.apply_node(node);
+                            text = text.map(|text| declaration.apply_text(text));
+                        }
+                    }
+                }
+                StyleLayer::Viewport => {
+                    (node, text) = layers.viewport.restyle(node, text);
+                }
+                StyleLayer::Inline => {
+                    if let Some(inline) = &inline {
+                        inline.redo(&mut node, &mut text, &mut image, &mut fonts);
+                    }
+                }
+                StyleLayer::State => {
+                    if let Some((state, styles)) = state {
+                        (node, text) = styles.restyle(*state, styled_by, node, text);
+                    }
+                }
+                _ => {}
+            }
+            let after =
+                StyleSnapshot::capture(&node, text.as_deref(), image.as_deref(), fonts.as_deref());
+            layers
+                .applied
+                .extend(AppliedLayer::record(layer, before, after));
+        }
+    }
+}
+
+fn insert_style_layers(
+    mut commands: Commands,
+    nodes: Query<(Entity, Has<UiLengths>), (Added<Node>, Without<StyleLayers>)>,
+) {
+    for (entity, has_lengths) in nodes.iter() {
+        let mut entity = commands.entity(entity);
+        entity.insert(StyleLayers::default());
+        if !has_lengths {
+            entity.insert(UiLengths::default());
+        }
+    }
+}
+
+pub struct StyleLayersPlugin;
+
+impl Plugin for StyleLayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .init_resource::<ClassStylers>()
+            .configure_sets(
+                Update,
+                (
+                    UiStyleSet::Theme,
+                    UiStyleSet::Stylesheet,
+                    UiStyleSet::Classes,
+                    UiStyleSet::Utilities,
+                    UiStyleSet::Viewport,
+                    UiStyleSet::State,
+                    UiStyleSet::Apply,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    insert_style_layers.before(UiStyleSet::Theme),
+                    apply_style_layers.in_set(UiStyleSet::Apply),
+                ),
+            );
+    }
+}
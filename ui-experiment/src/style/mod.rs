@@ -1,13 +1,42 @@
 mod background_color;
+mod classes;
+mod declaration;
+mod dyn_styler;
+mod font_family;
+pub mod grid;
+mod layers;
 mod node;
+mod rich_text;
+mod state;
+mod stylesheet;
 mod text;
+mod text_overflow;
+mod theme;
+mod transition;
+mod units;
+mod utility;
+mod viewport;
 
 pub use background_color::*;
+pub use classes::*;
+pub use declaration::*;
+pub use dyn_styler::*;
+pub use font_family::*;
+pub use layers::*;
 pub use node::*;
+pub use rich_text::*;
+pub use state::*;
+pub use stylesheet::*;
 pub use text::*;
+pub use text_overflow::*;
+pub use theme::*;
+pub use transition::*;
+pub use units::*;
+pub use utility::*;
+pub use viewport::*;
 
 use bevy::{
-    prelude::{Component, Handle, Image},
+    prelude::{Component, Handle, Image, Mut},
     ui::*,
 };
 
@@ -15,6 +44,13 @@ pub trait StyleComponentApplier<Inner: Default> {
     fn get_component<T: FnMut(&mut Inner)>(self, apply: T) -> Self;
 }
 
+impl<'a, Inner: Component + Default> StyleComponentApplier<Inner> for Mut<'a, Inner> {
+    fn get_component<T: FnMut(&mut Inner)>(mut self, mut apply: T) -> Self {
+        apply(&mut self);
+        self
+    }
+}
+
 pub trait TypedStyler<Input = ()> {
     fn typed_text_section_style<T: TextStyling>(&self, styled: T, input: Input) -> T;
     fn typed_text_style<T: TextApplier>(&self, styled: T, input: Input) -> T;
@@ -25,13 +61,29 @@ pub trait TypedStyler<Input = ()> {
     ) -> T;
 }
 
-pub trait Styler {
+pub trait Styler: Send + Sync + 'static {
     fn text_section_style<T: TextStyling>(&self, styled: T) -> T;
     fn text_style<T: TextApplier>(&self, styled: T) -> T;
     fn style<T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier>(
         &self,
         styled: T,
     ) -> T;
+
+    fn disabled_style<
+        T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier,
+    >(
+        &self,
+        styled: T,
+    ) -> T {
+        styled.desaturate(0.8, 0.6)
+    }
+
+    fn with_theme(&self, _theme: &Theme) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 impl<S: Styler> TypedStyler<()> for S {
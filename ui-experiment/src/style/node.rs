@@ -1,106 +1,193 @@
 use bevy::{prelude::Visibility, ui::*};
 
-use super::StyleComponentApplier;
+use super::{
+    LengthField, StyleComponentApplier, UiLengths, UiVal, UiValRect, DEFAULT_ROOT_FONT_SIZE,
+};
+
+pub trait Layout: StyleComponentApplier<Style> + StyleComponentApplier<UiLengths> + Sized {
+    fn length(self, field: LengthField, val: impl Into<UiVal>) -> Self {
+        let val = val.into();
+        let provisional = val.resolve(DEFAULT_ROOT_FONT_SIZE, DEFAULT_ROOT_FONT_SIZE);
+        self.get_component(move |v: &mut Style| *field.get_mut(v) = provisional)
+            .get_component(move |v: &mut UiLengths| v.set(field, val))
+    }
+
+    fn lengths(self, fields: [LengthField; 4], val: impl Into<UiValRect>) -> Self {
+        let val = val.into();
+        let [left, right, top, bottom] = fields;
+        self.length(left, val.left)
+            .length(right, val.right)
+            .length(top, val.top)
+            .length(bottom, val.bottom)
+    }
 
-pub trait Layout: StyleComponentApplier<Style> + Sized {
     fn display(self, val: Display) -> Self {
-        self.get_component(move |v| v.display = val)
+        self.get_component(move |v: &mut Style| v.display = val)
     }
     fn position_type(self, val: PositionType) -> Self {
-        self.get_component(move |v| v.position_type = val)
+        self.get_component(move |v: &mut Style| v.position_type = val)
     }
+
+    fn left(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Left, val)
+    }
+
+    fn right(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Right, val)
+    }
+
+    fn top(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Top, val)
+    }
+
+    fn bottom(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Bottom, val)
+    }
+
+    fn inset(self, val: impl Into<UiValRect>) -> Self {
+        self.lengths(
+            [
+                LengthField::Left,
+                LengthField::Right,
+                LengthField::Top,
+                LengthField::Bottom,
+            ],
+            val,
+        )
+    }
+
     fn direction(self, val: Direction) -> Self {
-        self.get_component(move |v| v.direction = val)
+        self.get_component(move |v: &mut Style| v.direction = val)
     }
 
     fn flex_direction(self, val: FlexDirection) -> Self {
-        self.get_component(move |v| v.flex_direction = val)
+        self.get_component(move |v: &mut Style| v.flex_direction = val)
     }
 
     fn flex_wrap(self, val: FlexWrap) -> Self {
-        self.get_component(move |v| v.flex_wrap = val)
+        self.get_component(move |v: &mut Style| v.flex_wrap = val)
     }
 
     fn align_items(self, val: AlignItems) -> Self {
-        self.get_component(move |v| v.align_items = val)
+        self.get_component(move |v: &mut Style| v.align_items = val)
+    }
+
+    fn justify_items(self, val: JustifyItems) -> Self {
+        self.get_component(move |v: &mut Style| v.justify_items = val)
     }
 
     fn align_self(self, val: AlignSelf) -> Self {
-        self.get_component(move |v| v.align_self = val)
+        self.get_component(move |v: &mut Style| v.align_self = val)
+    }
+
+    fn justify_self(self, val: JustifySelf) -> Self {
+        self.get_component(move |v: &mut Style| v.justify_self = val)
     }
 
     fn align_content(self, val: AlignContent) -> Self {
-        self.get_component(move |v| v.align_content = val)
+        self.get_component(move |v: &mut Style| v.align_content = val)
     }
 
     fn justify_content(self, val: JustifyContent) -> Self {
-        self.get_component(move |v| v.justify_content = val)
+        self.get_component(move |v: &mut Style| v.justify_content = val)
     }
 
-    fn margin(self, val: UiRect) -> Self {
-        self.get_component(move |v| v.margin = val)
+    fn margin(self, val: impl Into<UiValRect>) -> Self {
+        self.lengths(LengthField::MARGIN, val)
     }
 
-    fn padding(self, val: UiRect) -> Self {
-        self.get_component(move |v| v.padding = val)
+    fn padding(self, val: impl Into<UiValRect>) -> Self {
+        self.lengths(LengthField::PADDING, val)
     }
 
-    fn border(self, val: UiRect) -> Self {
-        self.get_component(move |v| v.border = val)
+    fn border(self, val: impl Into<UiValRect>) -> Self {
+        self.lengths(LengthField::BORDER, val)
     }
 
     fn flex_grow(self, val: f32) -> Self {
-        self.get_component(move |v| v.flex_grow = val)
+        self.get_component(move |v: &mut Style| v.flex_grow = val)
     }
 
     fn flex_shrink(self, val: f32) -> Self {
-        self.get_component(move |v| v.flex_shrink = val)
+        self.get_component(move |v: &mut Style| v.flex_shrink = val)
     }
 
-    fn flex_basis(self, val: Val) -> Self {
-        self.get_component(move |v| v.flex_basis = val)
+    fn flex_basis(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::FlexBasis, val)
     }
 
-    fn width(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.width = val)
+    fn width(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Width, val)
     }
 
-    fn min_width(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.min_width = val)
+    fn min_width(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::MinWidth, val)
     }
 
-    fn max_width(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.max_width = val)
+    fn max_width(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::MaxWidth, val)
     }
 
-    fn height(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.height = val)
+    fn height(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::Height, val)
     }
 
-    fn min_height(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.min_height = val)
+    fn min_height(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::MinHeight, val)
     }
 
-    fn max_height(self, val: Val) -> Self {
-        self.get_component(move |v: &mut Style| v.max_height = val)
+    fn max_height(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::MaxHeight, val)
     }
 
     fn aspect_ratio(self, val: Option<f32>) -> Self {
-        self.get_component(move |v| v.aspect_ratio = val)
+        self.get_component(move |v: &mut Style| v.aspect_ratio = val)
     }
 
     fn overflow(self, val: Overflow) -> Self {
-        self.get_component(move |v| v.overflow = val)
+        self.get_component(move |v: &mut Style| v.overflow = val)
+    }
+    fn row_gap(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::RowGap, val)
+    }
+    fn column_gap(self, val: impl Into<UiVal>) -> Self {
+        self.length(LengthField::ColumnGap, val)
+    }
+
+    fn grid_auto_flow(self, val: GridAutoFlow) -> Self {
+        self.get_component(move |v: &mut Style| v.grid_auto_flow = val)
+    }
+
+    fn grid_template_rows(self, val: impl Into<Vec<RepeatedGridTrack>>) -> Self {
+        let val = val.into();
+        self.get_component(move |v: &mut Style| v.grid_template_rows = val.clone())
     }
-    fn row_gap(self, val: Val) -> Self {
-        self.get_component(move |v| v.row_gap = val)
+
+    fn grid_template_columns(self, val: impl Into<Vec<RepeatedGridTrack>>) -> Self {
+        let val = val.into();
+        self.get_component(move |v: &mut Style| v.grid_template_columns = val.clone())
+    }
+
+    fn grid_auto_rows(self, val: impl Into<Vec<GridTrack>>) -> Self {
+        let val = val.into();
+        self.get_component(move |v: &mut Style| v.grid_auto_rows = val.clone())
+    }
+
+    fn grid_auto_columns(self, val: impl Into<Vec<GridTrack>>) -> Self {
+        let val = val.into();
+        self.get_component(move |v: &mut Style| v.grid_auto_columns = val.clone())
+    }
+
+    fn grid_row(self, val: GridPlacement) -> Self {
+        self.get_component(move |v: &mut Style| v.grid_row = val)
     }
-    fn column_gap(self, val: Val) -> Self {
-        self.get_component(move |v| v.column_gap = val)
+
+    fn grid_column(self, val: GridPlacement) -> Self {
+        self.get_component(move |v: &mut Style| v.grid_column = val)
     }
 }
 
-impl<T: StyleComponentApplier<Style> + Sized> Layout for T {}
+impl<T: StyleComponentApplier<Style> + StyleComponentApplier<UiLengths> + Sized> Layout for T {}
 
 pub trait FocusPolicyApplier: StyleComponentApplier<FocusPolicy> + Sized {
     fn focus_policy(self, val: FocusPolicy) -> Self {
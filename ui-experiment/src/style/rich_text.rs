@@ -0,0 +1,360 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy::{prelude::*, ui::UiSystem};
+
+use crate::{
+    parse_color, FontSelection, FontSelections, FontVariant, InternalUiSpawner, StyledBy, Styler,
+    TextLevel, Theme, UiBundleGenerator, UiBundleGeneratorStyler, UiComponent,
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichSpan {
+    pub text: String,
+    pub color: Option<String>,
+    pub size: Option<String>,
+    pub bold: bool,
+    pub italic: bool,
+    pub style: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl RichSpan {
+    pub fn text_style(&self, base: &TextStyle, theme: &Theme) -> TextStyle {
+        let mut style = self
+            .style
+            .as_deref()
+            .and_then(|name| name.parse::<TextLevel>().ok())
+            .and_then(|level| theme.text_styles.get(&level))
+            .cloned()
+            .unwrap_or_else(|| base.clone());
+        if let Some(color) = self.color.as_deref().and_then(|color| {
+            theme
+                .colors
+                .get(color)
+                .copied()
+                .or_else(|| parse_color(color).ok())
+        }) {
+            style.color = color;
+        }
+        if let Some(size) = self.size.as_deref().and_then(|size| {
+            theme
+                .font_sizes
+                .get(size)
+                .copied()
+                .or_else(|| size.parse().ok())
+        }) {
+            style.font_size = size;
+        }
+        style
+    }
+
+    pub fn font_selection(&self, base: &FontSelection) -> FontSelection {
+        FontSelection {
+            family: base.family.clone(),
+            variant: FontVariant {
+                bold: base.variant.bold || self.bold,
+                italic: base.variant.italic || self.italic,
+            },
+        }
+    }
+}
+
+const RICH_TEXT_TAGS: [&str; 6] = ["color", "size", "b", "i", "style", "tag"];
+
+fn span_from_tags(text: String, tags: &[(String, Option<String>)]) -> RichSpan {
+    let mut span = RichSpan {
+        text,
+        ..Default::default()
+    };
+    for (name, value) in tags {
+        match name.as_str() {
+            "color" => span.color = value.clone(),
+            "size" => span.size = value.clone(),
+            "b" => span.bold = true,
+            "i" => span.italic = true,
+            "style" => span.style = value.clone(),
+            "tag" => span.tag = value.clone(),
+            _ => {}
+        }
+    }
+    span
+}
+
+pub fn parse_rich_text(markup: &str) -> Vec<RichSpan> {
+    let mut spans = Vec::new();
+    let mut tags: Vec<(String, Option<String>)> = Vec::new();
+    let mut buffer = String::new();
+    let mut rest = markup;
+
+    let mut flush = |buffer: &mut String, tags: &[(String, Option<String>)]| {
+        if !buffer.is_empty() {
+            spans.push(span_from_tags(std::mem::take(buffer), tags));
+        }
+    };
+
+    while let Some(start) = rest.find('[') {
+        buffer.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(escaped) = rest.strip_prefix("[[") {
+            buffer.push('[');
+            rest = escaped;
+            continue;
+        }
+        let Some(end) = rest.find(']') else {
+            break;
+        };
+        let tag = rest[1..end].trim();
+        let literal = &rest[..=end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            match tags.iter().rposition(|(open, _)| *open == name) {
+                Some(index) => {
+                    flush(&mut buffer, &tags);
+                    tags.remove(index);
+                }
+                None => buffer.push_str(literal),
+            }
+            continue;
+        }
+
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name, Some(value.trim().trim_matches('"').to_string())),
+            None => (tag, None),
+        };
+        let name = name.trim().to_ascii_lowercase();
+        if RICH_TEXT_TAGS.contains(&name.as_str()) {
+            flush(&mut buffer, &tags);
+            tags.push((name, value));
+        } else {
+            buffer.push_str(literal);
+        }
+    }
+    buffer.push_str(rest);
+    flush(&mut buffer, &tags);
+    spans
+}
+
+type SectionStyler = Arc<dyn Fn(TextStyle) -> TextStyle + Send + Sync>;
+
+#[derive(Component, Clone)]
+pub struct RichText {
+    markup: String,
+    base: Option<TextStyle>,
+    base_font: Option<FontSelection>,
+    tagged: Vec<(String, SectionStyler)>,
+}
+
+impl RichText {
+    pub fn new(markup: impl Into<String>) -> Self {
+        Self {
+            markup: markup.into(),
+            base: None,
+            base_font: None,
+            tagged: Vec::new(),
+        }
+    }
+
+    pub fn with_section(
+        mut self,
+        tag: impl Into<String>,
+        style: impl Fn(TextStyle) -> TextStyle + Send + Sync + 'static,
+    ) -> Self {
+        self.set_section(tag, style);
+        self
+    }
+
+    pub fn set_section(
+        &mut self,
+        tag: impl Into<String>,
+        style: impl Fn(TextStyle) -> TextStyle + Send + Sync + 'static,
+    ) {
+        let tag = tag.into();
+        self.tagged.retain(|(existing, _)| *existing != tag);
+        self.tagged.push((tag, Arc::new(style)));
+    }
+
+    pub fn markup(&self) -> &str {
+        &self.markup
+    }
+
+    pub fn set_markup(&mut self, markup: impl Into<String>) {
+        self.markup = markup.into();
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn rich_text(self, markup: impl Into<String>) -> Self {
+        self.insert(RichText::new(markup))
+    }
+
+    pub fn tagged_section(
+        self,
+        tag: impl Into<String>,
+        style: impl Fn(TextStyle) -> TextStyle + Send + Sync + 'static,
+    ) -> Self {
+        let tag = tag.into();
+        self.with_inserter(move |commands| {
+            commands.add(move |entity: Entity, world: &mut World| {
+                if let Some(mut rich_text) = world.get_mut::<RichText>(entity) {
+                    rich_text.set_section(tag, style);
+                }
+            });
+        })
+    }
+}
+
+pub(crate) fn apply_rich_text(
+    theme: Res<Theme>,
+    mut texts: Query<(
+        &mut RichText,
+        &mut Text,
+        Option<&mut FontSelections>,
+        Option<&StyledBy>,
+    )>,
+) {
+    for (mut rich_text, mut text, fonts, styled_by) in texts.iter_mut() {
+        if !rich_text.is_changed() && !theme.is_changed() {
+            continue;
+        }
+        let rich_text = rich_text.bypass_change_detection();
+        let base = rich_text
+            .base
+            .get_or_insert_with(|| {
+                text.sections
+                    .first()
+                    .map(|section| section.style.clone())
+                    .unwrap_or_default()
+            })
+            .clone();
+        // Sections are rebuilt from markup, so the styler's section style is
+        // applied here rather than by `style_appended_sections`; markup and
+        // tagged sections still win over it.
+        let base = match styled_by {
+            Some(styled_by) => styled_by.0.section_style(base),
+            None => base,
+        };
+        let spans = parse_rich_text(&rich_text.markup);
+        if let Some(mut fonts) = fonts {
+            let base_font = rich_text
+                .base_font
+                .get_or_insert_with(|| fonts.get(0).cloned().unwrap_or_default())
+                .clone();
+            fonts.clear();
+            for (index, span) in spans.iter().enumerate() {
+                *fonts.section_mut(index) = span.font_selection(&base_font);
+            }
+        }
+        text.sections = spans
+            .into_iter()
+            .map(|span| {
+                let mut style = span.text_style(&base, &theme);
+                if let Some(tag) = span.tag.as_deref() {
+                    for (_, styler) in rich_text.tagged.iter().filter(|(name, _)| name == tag) {
+                        style = styler(style);
+                    }
+                }
+                TextSection::new(span.text, style)
+            })
+            .collect();
+    }
+}
+
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, apply_rich_text.before(UiSystem::Layout));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str) -> RichSpan {
+        RichSpan {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn nested_tags_accumulate_and_close_independently() {
+        let spans = parse_rich_text("a[b]b[color=red]c[i]d[/b]e[/i][/color]f");
+        assert_eq!(
+            spans,
+            [
+                span("a"),
+                RichSpan {
+                    bold: true,
+                    ..span("b")
+                },
+                RichSpan {
+                    bold: true,
+                    color: Some("red".into()),
+                    ..span("c")
+                },
+                RichSpan {
+                    bold: true,
+                    italic: true,
+                    color: Some("red".into()),
+                    ..span("d")
+                },
+                RichSpan {
+                    italic: true,
+                    color: Some("red".into()),
+                    ..span("e")
+                },
+                span("f"),
+            ]
+        );
+    }
+
+    #[test]
+    fn inner_values_override_outer_ones() {
+        let spans = parse_rich_text("[size=20]a[size=\"h1\"]b[/size]c[/size]");
+        let sizes: Vec<_> = spans
+            .iter()
+            .map(|span| (span.text.as_str(), span.size.as_deref()))
+            .collect();
+        assert_eq!(
+            sizes,
+            [("a", Some("20")), ("b", Some("h1")), ("c", Some("20"))]
+        );
+    }
+
+    #[test]
+    fn unclosed_and_unknown_tags_are_handled_leniently() {
+        assert_eq!(
+            parse_rich_text("[b]bold to the end"),
+            [RichSpan {
+                bold: true,
+                ..span("bold to the end")
+            }]
+        );
+        assert_eq!(
+            parse_rich_text("[x]a[/i]b[[c] [d"),
+            [span("[x]a[/i]b[c] [d")]
+        );
+        assert_eq!(
+            parse_rich_text("[TAG=Price]9[/tag]"),
+            [RichSpan {
+                tag: Some("Price".into()),
+                ..span("9")
+            }]
+        );
+    }
+}
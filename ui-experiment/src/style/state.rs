@@ -0,0 +1,176 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy::{ecs::query::Has, prelude::*};
+
+use crate::{
+    Checked, Disabled, DynStyler, InheritedDisabled, InternalUiSpawner, NodeComponentsMut,
+    StyleLayer, StyleLayers, StyledBy, Styler, UiBundleGenerator, UiBundleGeneratorStyler,
+    UiComponent, UiFocus, UiStyleSet,
+};
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UiState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+    pub disabled: bool,
+    pub checked: bool,
+}
+
+impl UiState {
+    pub fn is_base(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Component, Clone, Default)]
+pub struct StateStyles {
+    checked: Option<Arc<dyn DynStyler>>,
+    focus: Option<Arc<dyn DynStyler>>,
+    hover: Option<Arc<dyn DynStyler>>,
+    active: Option<Arc<dyn DynStyler>>,
+    disabled: Option<Arc<dyn DynStyler>>,
+}
+
+impl StateStyles {
+    pub fn checked<S: Styler>(mut self, styler: S) -> Self {
+        self.checked = Some(Arc::new(styler));
+        self
+    }
+
+    pub fn focus<S: Styler>(mut self, styler: S) -> Self {
+        self.focus = Some(Arc::new(styler));
+        self
+    }
+
+    pub fn hover<S: Styler>(mut self, styler: S) -> Self {
+        self.hover = Some(Arc::new(styler));
+        self
+    }
+
+    pub fn active<S: Styler>(mut self, styler: S) -> Self {
+        self.active = Some(Arc::new(styler));
+        self
+    }
+
+    pub fn disabled<S: Styler>(mut self, styler: S) -> Self {
+        self.disabled = Some(Arc::new(styler));
+        self
+    }
+
+    fn patches(&self, state: UiState) -> Vec<Arc<dyn DynStyler>> {
+        let patches = if state.disabled {
+            [(state.checked, &self.checked), (true, &self.disabled)].to_vec()
+        } else {
+            [
+                (state.checked, &self.checked),
+                (state.focused, &self.focus),
+                (state.hovered, &self.hover),
+                (state.pressed, &self.active),
+            ]
+            .to_vec()
+        };
+        patches
+            .into_iter()
+            .filter(|(active, _)| *active)
+            .filter_map(|(_, styler)| styler.clone())
+            .collect()
+    }
+
+    pub(crate) fn restyle<'a>(
+        &self,
+        state: UiState,
+        styled_by: Option<&StyledBy>,
+        mut node: NodeComponentsMut<'a>,
+        mut text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>) {
+        if state.is_base() {
+            return (node, text);
+        }
+        if state.disabled && self.disabled.is_none() {
+            if let Some(styled_by) = styled_by {
+                node = styled_by.0.restyle_disabled(node);
+            }
+        }
+        for styler in self.patches(state) {
+            (node, text) = styler.restyle(node, text);
+        }
+        (node, text)
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn state_styles(self, styles: StateStyles) -> Self {
+        self.with_inserter(move |commands| {
+            commands.insert((styles, UiState::default()));
+            commands.add(|entity: Entity, world: &mut World| {
+                let mut entity = world.entity_mut(entity);
+                if !entity.contains::<Interaction>() {
+                    entity.insert(Interaction::default());
+                }
+            });
+        })
+    }
+}
+
+fn update_ui_state(
+    focus: Res<UiFocus>,
+    mut states: Query<(
+        Entity,
+        &mut UiState,
+        Option<&Interaction>,
+        Has<Disabled>,
+        Has<InheritedDisabled>,
+        Option<&Checked>,
+    )>,
+) {
+    for (entity, mut state, interaction, disabled, inherited, checked) in states.iter_mut() {
+        let pressed = interaction == Some(&Interaction::Pressed);
+        let next = UiState {
+            hovered: pressed || interaction == Some(&Interaction::Hovered),
+            pressed,
+            focused: focus.is_focused(entity),
+            disabled: disabled || inherited,
+            checked: checked.is_some_and(|checked| checked.0),
+        };
+        if *state != next {
+            *state = next;
+        }
+    }
+}
+
+fn invalidate_state_styles(
+    mut nodes: Query<&mut StyleLayers, Or<(Changed<UiState>, Changed<StateStyles>)>>,
+) {
+    for mut layers in nodes.iter_mut() {
+        layers.invalidate(StyleLayer::State);
+    }
+}
+
+pub struct StateStylesPlugin;
+
+impl Plugin for StateStylesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<UiState>()
+            .register_type::<Disabled>()
+            .register_type::<Checked>()
+            .add_systems(
+                Update,
+                (update_ui_state, invalidate_state_styles)
+                    .chain()
+                    .in_set(UiStyleSet::State),
+            );
+    }
+}
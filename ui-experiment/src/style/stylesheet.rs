@@ -0,0 +1,587 @@
+use std::cell::RefCell;
+
+use bevy::{
+    asset::{AssetLoader, AssetPath, HandleId, LoadContext, LoadedAsset},
+    ecs::query::Has,
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+
+use crate::{
+    ButtonNode, ImageNode, StyleDeclaration, StyleLayer, StyleLayers, TextNode, UiClasses,
+    UiIdLabel, UiNode, UiStyleSet,
+};
+
+type ElementInfo<'a> = (
+    Has<UiNode>,
+    Has<TextNode>,
+    Has<ImageNode>,
+    Has<ButtonNode>,
+    Option<&'a UiIdLabel>,
+    Option<&'a UiClasses>,
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementKind {
+    Node,
+    Text,
+    Image,
+    Button,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompoundSelector {
+    pub kind: Option<ElementKind>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl CompoundSelector {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+        for (index, c) in source.char_indices() {
+            if index > 0 && (c == '.' || c == '#') {
+                segments.push(&source[start..index]);
+                start = index;
+            }
+        }
+        segments.push(&source[start..]);
+
+        let mut result = Self::default();
+        for (index, segment) in segments.into_iter().enumerate() {
+            if let Some(class) = segment.strip_prefix('.') {
+                result.classes.push(class.to_string());
+            } else if let Some(id) = segment.strip_prefix('#') {
+                result.id = Some(id.to_string());
+            } else if index == 0 {
+                result.kind = match segment {
+                    "*" => None,
+                    "UiNode" | "node" => Some(ElementKind::Node),
+                    "TextNode" | "text" => Some(ElementKind::Text),
+                    "ImageNode" | "image" => Some(ElementKind::Image),
+                    "ButtonNode" | "button" => Some(ElementKind::Button),
+                    _ => return Err(format!("unknown element `{segment}`")),
+                };
+            }
+        }
+        Ok(result)
+    }
+
+    fn matches(
+        &self,
+        (node, text, image, button, id, classes): (
+            bool,
+            bool,
+            bool,
+            bool,
+            Option<&UiIdLabel>,
+            Option<&UiClasses>,
+        ),
+    ) -> bool {
+        let kind_matches = match self.kind {
+            None => true,
+            Some(ElementKind::Node) => node,
+            Some(ElementKind::Text) => text,
+            Some(ElementKind::Image) => image,
+            Some(ElementKind::Button) => button,
+        };
+        let id_matches = match &self.id {
+            None => true,
+            Some(expected) => id.is_some_and(|id| id.as_str() == expected),
+        };
+        kind_matches
+            && id_matches
+            && self
+                .classes
+                .iter()
+                .all(|class| classes.is_some_and(|classes| classes.contains(class)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    pub subject: CompoundSelector,
+    pub ancestors: Vec<(Combinator, CompoundSelector)>,
+}
+
+impl Selector {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let source = source.replace('>', " > ");
+        let mut compounds = Vec::new();
+        let mut combinator = Combinator::Descendant;
+        for token in source.split_whitespace().rev() {
+            if token == ">" {
+                combinator = Combinator::Child;
+                continue;
+            }
+            compounds.push((combinator, CompoundSelector::parse(token)?));
+            combinator = Combinator::Descendant;
+        }
+        if compounds.is_empty() {
+            return Err("empty selector".to_string());
+        }
+        let (_, subject) = compounds.remove(0);
+        Ok(Self {
+            subject,
+            ancestors: compounds,
+        })
+    }
+
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        std::iter::once(&self.subject)
+            .chain(self.ancestors.iter().map(|(_, compound)| compound))
+            .fold((0, 0, 0), |(ids, classes, kinds), compound| {
+                (
+                    ids + usize::from(compound.id.is_some()),
+                    classes + compound.classes.len(),
+                    kinds + usize::from(compound.kind.is_some()),
+                )
+            })
+    }
+
+    fn matches(
+        &self,
+        entity: Entity,
+        elements: &Query<ElementInfo>,
+        parents: &Query<&Parent>,
+    ) -> bool {
+        elements
+            .get(entity)
+            .is_ok_and(|element| self.subject.matches(element))
+            && matches_ancestors(&self.ancestors, entity, elements, parents)
+    }
+}
+
+fn matches_ancestors(
+    ancestors: &[(Combinator, CompoundSelector)],
+    entity: Entity,
+    elements: &Query<ElementInfo>,
+    parents: &Query<&Parent>,
+) -> bool {
+    let Some(((combinator, compound), rest)) = ancestors.split_first() else {
+        return true;
+    };
+    let mut current = parents.get(entity).ok().map(|parent| parent.get());
+    while let Some(ancestor) = current {
+        if elements
+            .get(ancestor)
+            .is_ok_and(|element| compound.matches(element))
+            && matches_ancestors(rest, ancestor, elements, parents)
+        {
+            return true;
+        }
+        if *combinator == Combinator::Child {
+            return false;
+        }
+        current = parents.get(ancestor).ok().map(|parent| parent.get());
+    }
+    false
+}
+
+#[derive(Clone, Debug)]
+pub struct StyleRule {
+    pub selectors: Vec<Selector>,
+    pub declarations: Vec<StyleDeclaration>,
+}
+
+#[derive(Debug)]
+pub struct StylesheetError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for StylesheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "stylesheet error on line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for StylesheetError {}
+
+#[derive(Clone, Debug, Default, TypeUuid, TypePath)]
+#[uuid = "8e0a4a3c-7d5f-4f0e-9c51-2b6f3d1a9e47"]
+pub struct Stylesheet {
+    pub rules: Vec<StyleRule>,
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("*/")
+            .map_or(rest.len(), |end| start + end + 2);
+        result.extend(rest[start..end].chars().filter(|c| *c == '\n'));
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+impl Stylesheet {
+    pub fn parse(
+        source: &str,
+        load_font: &mut dyn FnMut(&str) -> Handle<Font>,
+        load_image: &mut dyn FnMut(&str) -> Handle<Image>,
+    ) -> Result<Self, StylesheetError> {
+        let source = strip_comments(source);
+        let mut rules = Vec::new();
+        let mut rest = source.as_str();
+        let mut line = 1;
+
+        while let Some(open) = rest.find('{') {
+            let error = |line: usize, message: String| StylesheetError { line, message };
+            let selectors_source = &rest[..open];
+            let selector_line = line + selectors_source.trim_end().matches('\n').count();
+            let Some(close) = rest[open..].find('}').map(|close| open + close) else {
+                return Err(error(selector_line, "unclosed block".to_string()));
+            };
+
+            let selectors = selectors_source
+                .split(',')
+                .map(Selector::parse)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|message| error(selector_line, message))?;
+
+            let mut declarations = Vec::new();
+            let mut declaration_line = line + rest[..=open].matches('\n').count();
+            for declaration in rest[open + 1..close].split(';') {
+                let leading = &declaration[..declaration.len() - declaration.trim_start().len()];
+                let current_line = declaration_line + leading.matches('\n').count();
+                declaration_line += declaration.matches('\n').count();
+                if declaration.trim().is_empty() {
+                    continue;
+                }
+                let Some((property, value)) = declaration.split_once(':') else {
+                    return Err(error(
+                        current_line,
+                        format!("expected `property: value`, found `{}`", declaration.trim()),
+                    ));
+                };
+                declarations.extend(
+                    StyleDeclaration::parse(property, value, load_font, load_image)
+                        .map_err(|message| error(current_line, message))?,
+                );
+            }
+
+            line += rest[..=close].matches('\n').count();
+            rest = &rest[close + 1..];
+            rules.push(StyleRule {
+                selectors,
+                declarations,
+            });
+        }
+
+        if !rest.trim().is_empty() {
+            return Err(StylesheetError {
+                line,
+                message: format!("unexpected `{}`", rest.trim()),
+            });
+        }
+        Ok(Self { rules })
+    }
+}
+
+#[derive(Default)]
+pub struct StylesheetLoader;
+
+impl AssetLoader for StylesheetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let source = std::str::from_utf8(bytes)?;
+            let dependencies = RefCell::new(Vec::new());
+            let stylesheet = Stylesheet::parse(
+                source,
+                &mut |path| {
+                    dependencies
+                        .borrow_mut()
+                        .push(AssetPath::from(path.to_string()));
+                    load_context.get_handle(path)
+                },
+                &mut |path| {
+                    dependencies
+                        .borrow_mut()
+                        .push(AssetPath::from(path.to_string()));
+                    load_context.get_handle(path)
+                },
+            )?;
+            load_context.set_default_asset(
+                LoadedAsset::new(stylesheet).with_dependencies(dependencies.into_inner()),
+            );
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["uss"]
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Stylesheets {
+    sheets: Vec<Handle<Stylesheet>>,
+}
+
+impl Stylesheets {
+    pub fn add(&mut self, stylesheet: Handle<Stylesheet>) {
+        if !self.sheets.contains(&stylesheet) {
+            self.sheets.push(stylesheet);
+        }
+    }
+
+    pub fn remove(&mut self, stylesheet: &Handle<Stylesheet>) {
+        self.sheets.retain(|existing| existing != stylesheet);
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MatchedRules {
+    rules: Vec<(HandleId, usize)>,
+    pub(crate) declarations: Vec<StyleDeclaration>,
+}
+
+fn collect_descendants(entity: Entity, children: &Query<&Children>, targets: &mut Vec<Entity>) {
+    if targets.contains(&entity) {
+        return;
+    }
+    targets.push(entity);
+    if let Ok(entity_children) = children.get(entity) {
+        for child in entity_children.iter() {
+            collect_descendants(*child, children, targets);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn match_stylesheets(
+    stylesheets: Res<Stylesheets>,
+    assets: Res<Assets<Stylesheet>>,
+    mut events: EventReader<AssetEvent<Stylesheet>>,
+    mut removed: RemovedComponents<UiClasses>,
+    changed: Query<
+        Entity,
+        Or<(
+            Added<StyleLayers>,
+            Changed<UiClasses>,
+            Changed<UiIdLabel>,
+            Changed<Parent>,
+        )>,
+    >,
+    elements: Query<ElementInfo>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut nodes: Query<(Entity, &mut StyleLayers)>,
+) {
+    let reloaded = events.iter().any(|event| match event {
+        AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+            stylesheets.sheets.contains(handle)
+        }
+        AssetEvent::Removed { .. } => false,
+    });
+
+    let mut targets = Vec::new();
+    if reloaded || stylesheets.is_changed() {
+        targets.extend(nodes.iter().map(|(entity, _)| entity));
+    } else {
+        for entity in changed.iter().chain(removed.iter()) {
+            collect_descendants(entity, &children, &mut targets);
+        }
+    }
+    if targets.is_empty() {
+        return;
+    }
+
+    let mut rules: Vec<((HandleId, usize), &Selector, &[StyleDeclaration])> = stylesheets
+        .sheets
+        .iter()
+        .filter_map(|handle| Some((handle.id(), assets.get(handle)?)))
+        .flat_map(|(id, sheet)| sheet.rules.iter().enumerate().map(move |rule| (id, rule)))
+        .flat_map(|(id, (index, rule))| {
+            rule.selectors
+                .iter()
+                .map(move |selector| ((id, index), selector, rule.declarations.as_slice()))
+        })
+        .collect();
+    rules.sort_by_key(|(_, selector, _)| selector.specificity());
+
+    for entity in targets {
+        let Ok((_, mut layers)) = nodes.get_mut(entity) else {
+            continue;
+        };
+        let mut matched = MatchedRules::default();
+        for (rule, selector, declarations) in rules.iter() {
+            if selector.matches(entity, &elements, &parents) {
+                matched.rules.push(*rule);
+                matched.declarations.extend(declarations.iter().cloned());
+            }
+        }
+        if matched.rules != layers.stylesheet.rules || (reloaded && !matched.rules.is_empty()) {
+            layers.stylesheet = matched;
+            layers.invalidate(StyleLayer::Stylesheet);
+        }
+    }
+}
+
+pub struct StylesheetPlugin;
+
+impl Plugin for StylesheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Stylesheet>()
+            .init_asset_loader::<StylesheetLoader>()
+            .init_resource::<Stylesheets>()
+            .add_systems(Update, match_stylesheets.in_set(UiStyleSet::Stylesheet));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+
+    use super::*;
+    use crate::{LengthField, UiVal};
+
+    fn parse(source: &str) -> Result<Stylesheet, StylesheetError> {
+        Stylesheet::parse(
+            source,
+            &mut |_: &str| Handle::<Font>::default(),
+            &mut |_: &str| Handle::<Image>::default(),
+        )
+    }
+
+    #[test]
+    fn selectors_parse_compounds_and_combinators() {
+        let selector = Selector::parse("node.card > text#5.title").unwrap();
+        assert_eq!(selector.subject.kind, Some(ElementKind::Text));
+        assert_eq!(selector.subject.id.as_deref(), Some("5"));
+        assert_eq!(selector.subject.classes, ["title"]);
+        assert_eq!(
+            selector.ancestors,
+            [(
+                Combinator::Child,
+                CompoundSelector {
+                    kind: Some(ElementKind::Node),
+                    id: None,
+                    classes: vec!["card".to_string()],
+                }
+            )]
+        );
+
+        let selector = Selector::parse("UiNode .a  *.b").unwrap();
+        assert_eq!(selector.subject.kind, None);
+        assert_eq!(selector.subject.classes, ["b"]);
+        let ancestors: Vec<_> = selector
+            .ancestors
+            .iter()
+            .map(|(combinator, compound)| (*combinator, compound.kind, compound.classes.clone()))
+            .collect();
+        assert_eq!(
+            ancestors,
+            [
+                (Combinator::Descendant, None, vec!["a".to_string()]),
+                (Combinator::Descendant, Some(ElementKind::Node), vec![]),
+            ]
+        );
+
+        assert!(Selector::parse("widget").is_err());
+        assert!(Selector::parse("  ").is_err());
+    }
+
+    #[test]
+    fn specificity_counts_ids_then_classes_then_elements() {
+        let specificity = |source| Selector::parse(source).unwrap().specificity();
+        assert_eq!(specificity("*"), (0, 0, 0));
+        assert_eq!(specificity("node > text.title"), (0, 1, 2));
+        assert_eq!(specificity(".card text#5"), (1, 1, 1));
+        assert!(specificity("#5") > specificity(".a.b.c text"));
+        assert!(specificity(".a") > specificity("node text"));
+    }
+
+    #[test]
+    fn stylesheets_parse_rules_and_report_lines() {
+        let sheet = parse(
+            "/* header\n   comment */\nnode, .card {\n  width: 10px;\n  height: 2em\n}\ntext { color: #ff0000; }\n",
+        )
+        .unwrap();
+        assert_eq!(sheet.rules.len(), 2);
+        assert_eq!(sheet.rules[0].selectors.len(), 2);
+        assert_eq!(sheet.rules[0].declarations.len(), 2);
+        assert!(matches!(
+            sheet.rules[0].declarations[1],
+            StyleDeclaration::Length(LengthField::Height, UiVal::Em(value)) if value == 2.
+        ));
+
+        let error = parse("node {\n  width: 10px;\n  height 5px;\n}").unwrap_err();
+        assert_eq!(error.line, 3);
+        let error = parse("\n\nwidget { width: 1px; }").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(parse("node { width: 1px;").is_err());
+        assert!(parse("node { width: 1px; } stray").is_err());
+    }
+
+    #[test]
+    fn matched_rules_apply_in_specificity_then_source_order() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StylesheetPlugin));
+        let sheet = parse(
+            "#5 { width: 30px; }\n\
+             .card text { width: 20px; }\n\
+             text { width: 10px; }\n\
+             node > text.title { width: 25px; }\n\
+             text { width: 15px; }\n\
+             image { width: 99px; }",
+        )
+        .unwrap();
+        let sheet = app.world.resource_mut::<Assets<Stylesheet>>().add(sheet);
+        app.world.resource_mut::<Stylesheets>().add(sheet);
+
+        let mut text = Entity::PLACEHOLDER;
+        let card = app
+            .world
+            .spawn((UiNode, UiClasses::new(["card"]), StyleLayers::default()))
+            .with_children(|card| {
+                text = card
+                    .spawn((
+                        TextNode,
+                        UiIdLabel::new(&5),
+                        UiClasses::new(["title"]),
+                        StyleLayers::default(),
+                    ))
+                    .id();
+            })
+            .id();
+        app.update();
+
+        let widths = |entity| -> Vec<f32> {
+            app.world
+                .get::<StyleLayers>(entity)
+                .unwrap()
+                .stylesheet
+                .declarations
+                .iter()
+                .filter_map(|declaration| match declaration {
+                    StyleDeclaration::Length(LengthField::Width, UiVal::Px(width)) => Some(*width),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(widths(text), [10., 15., 20., 25., 30.]);
+        assert!(widths(card).is_empty());
+    }
+}
@@ -9,7 +9,27 @@ use bevy::text::TextAlignment;
 use bevy::text::TextSection;
 use bevy::text::TextStyle;
 
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::prelude::{
+    App, Changed, Commands, Component, Entity, IntoSystemConfigs, Mut, Plugin, PostUpdate, Query,
+    Without,
+};
+use bevy::ui::UiSystem;
+
+use crate::FontSelection;
+use crate::FontSelections;
+use crate::InternalUiSpawner;
+use crate::RichText;
+use crate::StyledBy;
+use crate::TextOverflow;
+use crate::UiBundleGenerator;
+use crate::UiBundleGeneratorStyler;
+use crate::UiComponent;
+
 use super::StyleComponentApplier;
+use super::Styler;
 
 pub trait TextStyling: StyleComponentApplier<TextStyle> + Sized {
     fn text_color(self, color: Color) -> Self {
@@ -24,6 +44,14 @@ pub trait TextStyling: StyleComponentApplier<TextStyle> + Sized {
 }
 
 impl<T: StyleComponentApplier<TextStyle> + Sized> TextStyling for T {}
+
+impl StyleComponentApplier<TextStyle> for TextStyle {
+    fn get_component<T: FnMut(&mut TextStyle)>(mut self, mut apply: T) -> Self {
+        apply(&mut self);
+        self
+    }
+}
+
 pub trait TextApplier: StyleComponentApplier<Text> + Sized {
     fn set_text(self, value: impl Into<String>) -> Self {
         let value = value.into();
@@ -79,11 +107,163 @@ pub trait TextApplier: StyleComponentApplier<Text> + Sized {
         })
     }
 
+    fn section(self, index: usize, mut style: impl FnMut(TextStyle) -> TextStyle) -> Self {
+        self.get_component(move |v| {
+            if let Some(section) = v.sections.get_mut(index) {
+                section.style = style(std::mem::take(&mut section.style));
+            }
+        })
+    }
+
+    fn style_sections<S: Styler>(self, styler: &S, from: usize) -> Self {
+        self.get_component(move |v| {
+            for section in v.sections.iter_mut().skip(from) {
+                section.style = styler.text_section_style(std::mem::take(&mut section.style));
+            }
+        })
+    }
+
+    /// Font selection needs `FontSelections`, which only `UiTextBundle`
+    /// carries, so this and `font_family`/`bold`/`italic` are spawn-time
+    /// only; stylers running against query tuples can't call them.
+    fn select_font(self, mut select: impl FnMut(&mut FontSelection)) -> Self
+    where
+        Self: StyleComponentApplier<FontSelections>,
+    {
+        let mut last = 0;
+        let this = StyleComponentApplier::<Text>::get_component(self, |v| {
+            last = v.sections.len().saturating_sub(1)
+        });
+        StyleComponentApplier::<FontSelections>::get_component(this, move |v| {
+            select(v.section_mut(last))
+        })
+    }
+
+    fn font_family(self, name: impl Into<String>) -> Self
+    where
+        Self: StyleComponentApplier<FontSelections>,
+    {
+        let name = name.into();
+        self.select_font(move |v| v.family = Some(name.clone()))
+    }
+
+    fn bold(self) -> Self
+    where
+        Self: StyleComponentApplier<FontSelections>,
+    {
+        self.select_font(|v| v.variant = v.variant.bolded())
+    }
+
+    fn italic(self) -> Self
+    where
+        Self: StyleComponentApplier<FontSelections>,
+    {
+        self.select_font(|v| v.variant = v.variant.italicized())
+    }
+
     fn text_alignment(self, alignment: TextAlignment) -> Self {
         self.get_component(move |v| {
             v.alignment = alignment;
         })
     }
+
+    /// Like font selection, overflow settings live on `UiTextBundle` only.
+    fn ellipsis(self) -> Self
+    where
+        Self: StyleComponentApplier<TextOverflow>,
+    {
+        StyleComponentApplier::<TextOverflow>::get_component(self, |v| v.ellipsis = true)
+    }
+
+    fn max_lines(self, lines: usize) -> Self
+    where
+        Self: StyleComponentApplier<TextOverflow>,
+    {
+        StyleComponentApplier::<TextOverflow>::get_component(self, |v| {
+            v.max_lines = Some(lines.max(1))
+        })
+    }
+
+    fn auto_fit(self, min_font_size: f32) -> Self
+    where
+        Self: StyleComponentApplier<TextOverflow>,
+    {
+        StyleComponentApplier::<TextOverflow>::get_component(self, |v| {
+            v.auto_fit = Some(min_font_size)
+        })
+    }
 }
 
 impl<T: StyleComponentApplier<Text> + Sized> TextApplier for T {}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler + StyleComponentApplier<Text>,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn append_text(self, value: impl Into<String>) -> Self {
+        let value: String = value.into();
+        let styler = self.styler.clone();
+        self.get_component(move |v: &mut Text| {
+            let style = v
+                .sections
+                .first()
+                .map(|section| section.style.clone())
+                .unwrap_or_default();
+            let style = styler.text_section_style(style);
+            v.sections.push(TextSection::new(value.clone(), style));
+        })
+    }
+}
+
+pub(crate) fn replace_text_value(text: &mut Mut<Text>, value: String) {
+    if text.sections.len() == 1 && text.sections[0].value == value {
+        return;
+    }
+    let style = text
+        .sections
+        .first()
+        .map(|section| section.style.clone())
+        .unwrap_or_default();
+    text.sections = vec![TextSection::new(value, style)];
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+struct StyledSections(usize);
+
+/// Rich text is skipped: `apply_rich_text` rebuilds every section and runs
+/// the section styler itself.
+pub(crate) fn style_appended_sections(
+    mut commands: Commands,
+    mut texts: Query<
+        (Entity, &StyledBy, &mut Text, Option<&mut StyledSections>),
+        (Changed<Text>, Without<RichText>),
+    >,
+) {
+    for (entity, styled_by, mut text, styled) in texts.iter_mut() {
+        let count = text.sections.len();
+        let Some(mut styled) = styled else {
+            commands.entity(entity).insert(StyledSections(count));
+            continue;
+        };
+        if count > styled.0 {
+            styled_by.0.restyle_sections(&mut text, styled.0);
+        }
+        if styled.0 != count {
+            styled.0 = count;
+        }
+    }
+}
+
+pub struct TextSectionStylePlugin;
+
+impl Plugin for TextSectionStylePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, style_appended_sections.before(UiSystem::Layout));
+    }
+}
@@ -0,0 +1,363 @@
+use bevy::{
+    prelude::*,
+    text::TextMeasureInfo,
+    ui::{widget::text_system, UiSystem},
+};
+
+const ELLIPSIS: &str = "…";
+
+const FIT_TOLERANCE: f32 = 0.5;
+
+#[derive(Clone, Debug)]
+struct FittedText {
+    text: Text,
+    scale: f32,
+    bounds: Vec2,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct TextOverflow {
+    pub ellipsis: bool,
+    pub max_lines: Option<usize>,
+    pub auto_fit: Option<f32>,
+    source: Option<Text>,
+    applied: Option<FittedText>,
+}
+
+impl TextOverflow {
+    pub fn is_active(&self) -> bool {
+        self.ellipsis || self.max_lines.is_some() || self.auto_fit.is_some()
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        match (&self.source, &self.applied) {
+            (Some(source), Some(applied)) => !same_values(source, &applied.text),
+            _ => false,
+        }
+    }
+
+    pub fn full_text(&self) -> Option<&Text> {
+        self.source.as_ref()
+    }
+
+    fn line_limit(&self) -> Option<usize> {
+        self.max_lines.or(self.ellipsis.then_some(1))
+    }
+}
+
+fn same_values(a: &Text, b: &Text) -> bool {
+    a.sections.len() == b.sections.len()
+        && a.sections
+            .iter()
+            .zip(b.sections.iter())
+            .all(|(a, b)| a.value == b.value)
+}
+
+fn same_layout(a: &Text, b: &Text) -> bool {
+    same_values(a, b)
+        && a.sections
+            .iter()
+            .zip(b.sections.iter())
+            .all(|(a, b)| a.style.font == b.style.font && a.style.font_size == b.style.font_size)
+}
+
+fn same_text(a: &Text, b: &Text) -> bool {
+    same_layout(a, b)
+        && a.sections
+            .iter()
+            .zip(b.sections.iter())
+            .all(|(a, b)| a.style.color == b.style.color)
+}
+
+fn restyled(source: &Text, current: &Text, scale: f32) -> Text {
+    let mut text = source.clone();
+    text.alignment = current.alignment;
+    text.linebreak_behavior = current.linebreak_behavior;
+    for (section, current) in text.sections.iter_mut().zip(current.sections.iter()) {
+        section.style = current.style.clone();
+        section.style.font_size = current.style.font_size / scale;
+    }
+    text
+}
+
+fn scaled(source: &Text, scale: f32) -> Text {
+    let mut text = source.clone();
+    for section in text.sections.iter_mut() {
+        section.style.font_size *= scale;
+    }
+    text
+}
+
+fn truncated(source: &Text, keep: usize, suffix: &str) -> Text {
+    let mut text = source.clone();
+    let mut remaining = keep;
+    let mut tail = 0;
+    for (index, section) in text.sections.iter_mut().enumerate() {
+        let count = section.value.chars().count();
+        section.value = section.value.chars().take(remaining).collect();
+        if remaining > 0 {
+            tail = index;
+        }
+        remaining -= count.min(remaining);
+    }
+    if let Some(section) = text.sections.get_mut(tail) {
+        section.value = format!("{}{suffix}", section.value.trim_end());
+    }
+    text
+}
+
+struct TextMeasurer<'a> {
+    fonts: &'a Assets<Font>,
+    bounds: Vec2,
+}
+
+impl<'a> TextMeasurer<'a> {
+    fn size(&self, text: &Text, width: f32) -> Option<Vec2> {
+        TextMeasureInfo::from_text(text, self.fonts, 1.)
+            .ok()
+            .map(|info| info.compute_size(Vec2::new(width, f32::INFINITY)))
+    }
+
+    fn line_height(&self, text: &Text) -> Option<f32> {
+        text.sections.iter().try_fold(0f32, |height, section| {
+            let line = Text::from_section("M", section.style.clone());
+            Some(height.max(self.size(&line, f32::INFINITY)?.y))
+        })
+    }
+
+    fn fits(&self, text: &Text, overflow: &TextOverflow) -> Option<bool> {
+        let size = self.size(text, self.bounds.x)?;
+        let mut height = if overflow.auto_fit.is_some() {
+            self.bounds.y
+        } else {
+            f32::INFINITY
+        };
+        if let Some(lines) = overflow.line_limit() {
+            height = height.min(lines as f32 * self.line_height(text)?);
+        }
+        Some(size.x <= self.bounds.x + FIT_TOLERANCE && size.y <= height + FIT_TOLERANCE)
+    }
+
+    fn fit(&self, source: &Text, overflow: &TextOverflow) -> Option<(Text, f32)> {
+        if self.fits(source, overflow)? {
+            return Some((source.clone(), 1.));
+        }
+
+        let mut scale = 1.;
+        let largest = source
+            .sections
+            .iter()
+            .map(|section| section.style.font_size)
+            .fold(0f32, f32::max);
+        if let Some(min_size) = overflow.auto_fit.filter(|min_size| *min_size < largest) {
+            let (mut low, mut high) = (min_size / largest, 1.);
+            if self.fits(&scaled(source, low), overflow)? {
+                for _ in 0..8 {
+                    let mid = (low + high) / 2.;
+                    if self.fits(&scaled(source, mid), overflow)? {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+            }
+            scale = low;
+        }
+        let text = scaled(source, scale);
+        if overflow.line_limit().is_none() || self.fits(&text, overflow)? {
+            return Some((text, scale));
+        }
+
+        let suffix = if overflow.ellipsis { ELLIPSIS } else { "" };
+        let total = text
+            .sections
+            .iter()
+            .map(|section| section.value.chars().count())
+            .sum::<usize>();
+        let (mut low, mut high) = (0, total);
+        while low < high {
+            let mid = (low + high + 1) / 2;
+            if self.fits(&truncated(&text, mid, suffix), overflow)? {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Some((truncated(&text, low, suffix), scale))
+    }
+
+    fn refit(
+        &self,
+        overflow: &mut TextOverflow,
+        text: &Text,
+        config_changed: bool,
+    ) -> Option<Text> {
+        match &overflow.applied {
+            Some(applied) if same_values(&applied.text, text) => {
+                let moved = (applied.bounds - self.bounds).abs().max_element() > FIT_TOLERANCE;
+                let relayout = config_changed || moved || !same_layout(&applied.text, text);
+                if !same_text(&applied.text, text) {
+                    let scale = applied.scale;
+                    let source = overflow.source.as_ref().unwrap_or(text);
+                    overflow.source = Some(restyled(source, text, scale));
+                    if !relayout {
+                        if let Some(applied) = &mut overflow.applied {
+                            applied.text = text.clone();
+                        }
+                    }
+                }
+                if !relayout {
+                    return None;
+                }
+            }
+            _ => overflow.source = Some(text.clone()),
+        }
+
+        let source = overflow.source.as_ref()?;
+        let (fitted, scale) = self.fit(source, overflow)?;
+        overflow.applied = Some(FittedText {
+            text: fitted.clone(),
+            scale,
+            bounds: self.bounds,
+        });
+        (!same_text(&fitted, text)).then_some(fitted)
+    }
+}
+
+fn px(val: Val) -> Option<f32> {
+    match val {
+        Val::Px(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn container_bounds(node: &Node, style: &Style, container: Option<(&Node, &Style)>) -> Vec2 {
+    let own = node.size();
+    let Some((parent, parent_style)) = container else {
+        return own;
+    };
+    let inset = |start: Val, end: Val| px(start).unwrap_or(0.) + px(end).unwrap_or(0.);
+    let horizontal = inset(parent_style.padding.left, parent_style.padding.right)
+        + inset(parent_style.border.left, parent_style.border.right)
+        + inset(style.margin.left, style.margin.right);
+    let vertical = inset(parent_style.padding.top, parent_style.padding.bottom)
+        + inset(parent_style.border.top, parent_style.border.bottom)
+        + inset(style.margin.top, style.margin.bottom);
+    let mut bounds = (parent.size() - Vec2::new(horizontal, vertical)).max(Vec2::ZERO);
+    for limit in [style.width, style.max_width].into_iter().filter_map(px) {
+        bounds.x = bounds.x.min(limit);
+    }
+    for limit in [style.height, style.max_height].into_iter().filter_map(px) {
+        bounds.y = bounds.y.min(limit);
+    }
+    bounds
+}
+
+pub(crate) fn fit_text_overflow(
+    fonts: Res<Assets<Font>>,
+    mut texts: Query<(&mut TextOverflow, &mut Text, &Node, &Style, Option<&Parent>)>,
+    containers: Query<(&Node, &Style)>,
+) {
+    for (mut overflow, mut text, node, style, parent) in texts.iter_mut() {
+        let config_changed = overflow.is_changed();
+        let overflow = overflow.bypass_change_detection();
+        if !overflow.is_active() {
+            let applied = overflow.applied.take();
+            if let Some(source) = overflow.source.take() {
+                if applied.is_some_and(|applied| same_text(&applied.text, &text)) {
+                    *text = source;
+                }
+            }
+            continue;
+        }
+
+        let container = parent.and_then(|parent| containers.get(parent.get()).ok());
+        let measurer = TextMeasurer {
+            fonts: &fonts,
+            bounds: container_bounds(node, style, container),
+        };
+        if let Some(fitted) = measurer.refit(overflow, &text, config_changed) {
+            *text = fitted;
+        }
+    }
+}
+
+pub struct TextOverflowPlugin;
+
+impl Plugin for TextOverflowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            fit_text_overflow
+                .after(UiSystem::Layout)
+                .before(text_system),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+
+    use super::*;
+
+    const LONG: &str = "A sentence that is far too long to fit on one narrow line";
+
+    fn refit(app: &App, overflow: &mut TextOverflow, text: &Text, width: f32) -> Option<Text> {
+        let measurer = TextMeasurer {
+            fonts: app.world.resource::<Assets<Font>>(),
+            bounds: Vec2::new(width, 40.),
+        };
+        measurer.refit(overflow, text, false)
+    }
+
+    #[test]
+    fn ellipsis_refits_on_resize_but_not_on_recolor() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .add_asset::<Font>();
+        let font = Font::try_from_bytes(
+            include_bytes!("../../assets/libre-baskerville/LibreBaskerville-Regular.ttf").to_vec(),
+        )
+        .unwrap();
+        let font = app.world.resource_mut::<Assets<Font>>().add(font);
+        let mut overflow = TextOverflow {
+            ellipsis: true,
+            ..default()
+        };
+        let source = Text::from_section(
+            LONG,
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.,
+                color: Color::WHITE,
+            },
+        );
+
+        let mut text = refit(&app, &mut overflow, &source, 120.).expect("text is truncated");
+        assert!(overflow.is_truncated());
+        assert!(text.sections[0].value.ends_with(ELLIPSIS));
+
+        let wide = refit(&app, &mut overflow, &text, 2000.).expect("text is restored");
+        assert_eq!(wide.sections[0].value, LONG);
+        assert!(!overflow.is_truncated());
+        text = refit(&app, &mut overflow, &wide, 120.).expect("text is truncated again");
+
+        // Without the font any measurement fails, so a recolor must not measure at all.
+        app.world.resource_mut::<Assets<Font>>().remove(&font);
+        text.sections[0].style.color = Color::RED;
+        assert!(refit(&app, &mut overflow, &text, 120.).is_none());
+        assert!(overflow.is_truncated());
+        assert_eq!(
+            overflow.applied.as_ref().unwrap().text.sections[0]
+                .style
+                .color,
+            Color::RED
+        );
+        assert_eq!(overflow.full_text().unwrap().sections[0].value, LONG);
+        assert_eq!(
+            overflow.full_text().unwrap().sections[0].style.color,
+            Color::RED
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    BgColor, FocusPolicyApplier, Layout, StyleLayer, StyleLayers, StyledBy, Styler, TextApplier,
+    TextStyling, UiStyleSet, UiVal, VisibilityApplier, ZIndexApplier,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum TextLevel {
+    Display,
+    H1,
+    H2,
+    H3,
+    Body,
+    Label,
+    Caption,
+}
+
+impl std::str::FromStr for TextLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value.to_ascii_lowercase().as_str() {
+            "display" => TextLevel::Display,
+            "h1" => TextLevel::H1,
+            "h2" => TextLevel::H2,
+            "h3" => TextLevel::H3,
+            "body" => TextLevel::Body,
+            "label" => TextLevel::Label,
+            "caption" => TextLevel::Caption,
+            _ => return Err(format!("unknown text level `{value}`")),
+        })
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Theme {
+    pub colors: HashMap<String, Color>,
+    pub spacing: Vec<f32>,
+    pub radii: HashMap<String, f32>,
+    pub fonts: HashMap<String, Handle<Font>>,
+    pub font_sizes: HashMap<String, f32>,
+    pub text_styles: HashMap<TextLevel, TextStyle>,
+}
+
+impl Theme {
+    pub fn with_color(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.colors.insert(name.into(), color);
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: impl Into<Vec<f32>>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    pub fn with_radius(mut self, name: impl Into<String>, radius: f32) -> Self {
+        self.radii.insert(name.into(), radius);
+        self
+    }
+
+    pub fn with_font(mut self, name: impl Into<String>, font: Handle<Font>) -> Self {
+        self.fonts.insert(name.into(), font);
+        self
+    }
+
+    pub fn with_font_size(mut self, name: impl Into<String>, size: f32) -> Self {
+        self.font_sizes.insert(name.into(), size);
+        self
+    }
+
+    pub fn with_text_style(mut self, level: TextLevel, style: TextStyle) -> Self {
+        self.text_styles.insert(level, style);
+        self
+    }
+
+    pub fn color(&self, name: &str) -> Color {
+        self.colors.get(name).copied().unwrap_or(Color::FUCHSIA)
+    }
+
+    pub fn space(&self, step: usize) -> f32 {
+        self.spacing
+            .get(step)
+            .or(self.spacing.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn space_val(&self, step: usize) -> UiVal {
+        UiVal::Px(self.space(step))
+    }
+
+    pub fn radius(&self, name: &str) -> f32 {
+        self.radii.get(name).copied().unwrap_or_default()
+    }
+
+    pub fn font(&self, name: &str) -> Handle<Font> {
+        self.fonts.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn font_size(&self, name: &str) -> f32 {
+        self.font_sizes
+            .get(name)
+            .copied()
+            .unwrap_or(TextStyle::default().font_size)
+    }
+
+    pub fn text_style(&self, level: TextLevel) -> TextStyle {
+        self.text_styles.get(&level).cloned().unwrap_or_default()
+    }
+
+    pub fn styler<S: ThemeStyler>(&self, styler: S) -> Themed<S> {
+        Themed {
+            styler: Arc::new(styler),
+            theme: Arc::new(self.clone()),
+        }
+    }
+}
+
+pub trait ThemeStyler: Send + Sync + 'static {
+    fn text_section_style<T: TextStyling>(&self, _theme: &Theme, styled: T) -> T {
+        styled
+    }
+
+    fn text_style<T: TextApplier>(&self, _theme: &Theme, styled: T) -> T {
+        styled
+    }
+
+    fn style<T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier>(
+        &self,
+        _theme: &Theme,
+        styled: T,
+    ) -> T {
+        styled
+    }
+
+    fn disabled_style<
+        T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier,
+    >(
+        &self,
+        _theme: &Theme,
+        styled: T,
+    ) -> T {
+        styled.desaturate(0.8, 0.6)
+    }
+}
+
+pub struct Themed<S: ThemeStyler> {
+    styler: Arc<S>,
+    theme: Arc<Theme>,
+}
+
+impl<S: ThemeStyler> Themed<S> {
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+}
+
+impl<S: ThemeStyler> Clone for Themed<S> {
+    fn clone(&self) -> Self {
+        Self {
+            styler: self.styler.clone(),
+            theme: self.theme.clone(),
+        }
+    }
+}
+
+impl<S: ThemeStyler> Styler for Themed<S> {
+    fn text_section_style<T: TextStyling>(&self, styled: T) -> T {
+        self.styler.text_section_style(&self.theme, styled)
+    }
+
+    fn text_style<T: TextApplier>(&self, styled: T) -> T {
+        self.styler.text_style(&self.theme, styled)
+    }
+
+    fn style<T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier>(
+        &self,
+        styled: T,
+    ) -> T {
+        self.styler.style(&self.theme, styled)
+    }
+
+    fn disabled_style<
+        T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier,
+    >(
+        &self,
+        styled: T,
+    ) -> T {
+        self.styler.disabled_style(&self.theme, styled)
+    }
+
+    fn with_theme(&self, theme: &Theme) -> Option<Self> {
+        Some(Self {
+            styler: self.styler.clone(),
+            theme: Arc::new(theme.clone()),
+        })
+    }
+}
+
+fn restyle_on_theme_change(theme: Res<Theme>, mut nodes: Query<(&mut StyledBy, &mut StyleLayers)>) {
+    if !theme.is_changed() || theme.is_added() {
+        return;
+    }
+    for (mut styled_by, mut layers) in nodes.iter_mut() {
+        if let Some(styler) = styled_by.0.rethemed(&theme) {
+            styled_by.0 = styler;
+        }
+        layers.invalidate(StyleLayer::Base);
+    }
+}
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .add_systems(Update, restyle_on_theme_change.in_set(UiStyleSet::Theme));
+    }
+}
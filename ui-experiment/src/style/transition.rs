@@ -0,0 +1,446 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{prelude::*, ui::UiSystem};
+
+use crate::{
+    resolve_relative_lengths, InternalUiSpawner, LengthField, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiComponent,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1. - (1. - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+    let curve = |a: f32, b: f32, s: f32| {
+        let inv = 1. - s;
+        3. * inv * inv * s * a + 3. * inv * s * s * b + s * s * s
+    };
+    let (mut low, mut high) = (0., 1.);
+    let mut s = t;
+    for _ in 0..16 {
+        let x = curve(x1, x2, s);
+        if (x - t).abs() < 1e-4 {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.;
+    }
+    curve(y1, y2, s)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum TransitionProperty {
+    BackgroundColor,
+    TextColor,
+    Length(LengthField),
+}
+
+impl TransitionProperty {
+    pub const WIDTH: TransitionProperty = TransitionProperty::Length(LengthField::Width);
+    pub const HEIGHT: TransitionProperty = TransitionProperty::Length(LengthField::Height);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TweenValue {
+    Color(Color),
+    Colors(Vec<Color>),
+    Val(Val),
+}
+
+impl TweenValue {
+    fn lerp(&self, to: &TweenValue, t: f32) -> TweenValue {
+        match (self, to) {
+            (TweenValue::Color(from), TweenValue::Color(to)) => {
+                TweenValue::Color(lerp_color(*from, *to, t))
+            }
+            (TweenValue::Colors(from), TweenValue::Colors(to)) if from.len() == to.len() => {
+                TweenValue::Colors(
+                    from.iter()
+                        .zip(to.iter())
+                        .map(|(from, to)| lerp_color(*from, *to, t))
+                        .collect(),
+                )
+            }
+            (TweenValue::Val(from), TweenValue::Val(to)) => {
+                TweenValue::Val(lerp_val(*from, *to, t).unwrap_or(*to))
+            }
+            _ => to.clone(),
+        }
+    }
+}
+
+pub(crate) fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let [r0, g0, b0, a0] = from.as_rgba_f32();
+    let [r1, g1, b1, a1] = to.as_rgba_f32();
+    Color::rgba(
+        r0 + (r1 - r0) * t,
+        g0 + (g1 - g0) * t,
+        b0 + (b1 - b0) * t,
+        a0 + (a1 - a0) * t,
+    )
+}
+
+pub(crate) fn lerp_val(from: Val, to: Val, t: f32) -> Option<Val> {
+    let mix = |a: f32, b: f32| a + (b - a) * t;
+    Some(match (from, to) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(mix(a, b)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(mix(a, b)),
+        (Val::Vw(a), Val::Vw(b)) => Val::Vw(mix(a, b)),
+        (Val::Vh(a), Val::Vh(b)) => Val::Vh(mix(a, b)),
+        (Val::VMin(a), Val::VMin(b)) => Val::VMin(mix(a, b)),
+        (Val::VMax(a), Val::VMax(b)) => Val::VMax(mix(a, b)),
+        _ => return None,
+    })
+}
+
+#[derive(Clone, Debug)]
+struct Tween {
+    property: TransitionProperty,
+    from: TweenValue,
+    to: TweenValue,
+    elapsed: f32,
+}
+
+impl Tween {
+    fn value(&self, duration: f32, easing: Easing) -> (TweenValue, bool) {
+        let t = if duration > 0. {
+            self.elapsed / duration
+        } else {
+            1.
+        };
+        (self.from.lerp(&self.to, easing.apply(t)), t >= 1.)
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Transition {
+    pub properties: Vec<TransitionProperty>,
+    pub duration: f32,
+    pub easing: Easing,
+    targets: Vec<(TransitionProperty, TweenValue)>,
+    tweens: Vec<Tween>,
+}
+
+impl Transition {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            properties: Vec::new(),
+            duration,
+            easing: Easing::default(),
+            targets: Vec::new(),
+            tweens: Vec::new(),
+        }
+    }
+
+    pub fn property(mut self, property: TransitionProperty) -> Self {
+        if !self.properties.contains(&property) {
+            self.properties.push(property);
+        }
+        self
+    }
+
+    pub fn properties(self, properties: impl IntoIterator<Item = TransitionProperty>) -> Self {
+        properties
+            .into_iter()
+            .fold(self, |transition, property| transition.property(property))
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.tweens.is_empty()
+    }
+
+    fn target(&self, property: TransitionProperty) -> Option<TweenValue> {
+        self.targets
+            .iter()
+            .find(|(existing, _)| *existing == property)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn retarget(&mut self, property: TransitionProperty, to: TweenValue) {
+        let previous = self.target(property);
+        self.targets.retain(|(existing, _)| *existing != property);
+        self.targets.push((property, to.clone()));
+        let Some(previous) = previous else {
+            return;
+        };
+
+        let from = self
+            .tweens
+            .iter()
+            .find(|tween| tween.property == property)
+            .map_or(previous, |tween| tween.value(self.duration, self.easing).0);
+        self.tweens.retain(|tween| tween.property != property);
+        if self.duration > 0. {
+            self.tweens.push(Tween {
+                property,
+                from,
+                to,
+                elapsed: 0.,
+            });
+        }
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn transition(self, transition: Transition) -> Self {
+        self.insert(transition)
+    }
+}
+
+struct Animated<'a> {
+    style: Mut<'a, Style>,
+    background_color: Option<Mut<'a, BackgroundColor>>,
+    text: Option<Mut<'a, Text>>,
+}
+
+impl<'a> Animated<'a> {
+    fn get(&mut self, property: TransitionProperty) -> Option<TweenValue> {
+        match property {
+            TransitionProperty::BackgroundColor => self
+                .background_color
+                .as_ref()
+                .map(|background_color| TweenValue::Color(background_color.0)),
+            TransitionProperty::TextColor => self.text.as_ref().map(|text| {
+                TweenValue::Colors(
+                    text.sections
+                        .iter()
+                        .map(|section| section.style.color)
+                        .collect(),
+                )
+            }),
+            TransitionProperty::Length(field) => Some(TweenValue::Val(
+                *field.get_mut(self.style.bypass_change_detection()),
+            )),
+        }
+    }
+
+    fn set(&mut self, property: TransitionProperty, value: TweenValue, notify: bool) {
+        if self.get(property).as_ref() == Some(&value) {
+            return;
+        }
+        match (property, value) {
+            (TransitionProperty::BackgroundColor, TweenValue::Color(color)) => {
+                if let Some(background_color) = &mut self.background_color {
+                    background_color.bypass_change_detection().0 = color;
+                    if notify {
+                        background_color.set_changed();
+                    }
+                }
+            }
+            (TransitionProperty::TextColor, TweenValue::Colors(colors)) => {
+                if let Some(text) = &mut self.text {
+                    let sections = text.bypass_change_detection().sections.iter_mut();
+                    for (section, color) in sections.zip(colors) {
+                        section.style.color = color;
+                    }
+                    if notify {
+                        text.set_changed();
+                    }
+                }
+            }
+            (TransitionProperty::Length(field), TweenValue::Val(val)) => {
+                *field.get_mut(self.style.bypass_change_detection()) = val;
+                if notify {
+                    self.style.set_changed();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+type TransitionComponents<'a> = (
+    &'a mut Transition,
+    &'a mut Style,
+    Option<&'a mut BackgroundColor>,
+    Option<&'a mut Text>,
+);
+
+fn restore_transition_targets(mut nodes: Query<TransitionComponents>) {
+    for (transition, style, background_color, text) in nodes.iter_mut() {
+        if !transition.is_running() {
+            continue;
+        }
+        let mut animated = Animated {
+            style,
+            background_color,
+            text,
+        };
+        for tween in transition.tweens.iter() {
+            animated.set(tween.property, tween.to.clone(), false);
+        }
+    }
+}
+
+fn advance_transitions(time: Res<Time>, mut nodes: Query<TransitionComponents>) {
+    let delta = time.delta_seconds();
+    for (mut transition, style, background_color, text) in nodes.iter_mut() {
+        let transition = transition.bypass_change_detection();
+        let mut animated = Animated {
+            style,
+            background_color,
+            text,
+        };
+
+        for property in transition.properties.clone() {
+            let Some(actual) = animated.get(property) else {
+                continue;
+            };
+            if transition.target(property) != Some(actual) {
+                transition.retarget(property, actual);
+            }
+        }
+
+        let (duration, easing) = (transition.duration, transition.easing);
+        transition.tweens.retain_mut(|tween| {
+            tween.elapsed += delta;
+            let (value, finished) = tween.value(duration, easing);
+            animated.set(tween.property, value, true);
+            !finished
+        });
+    }
+}
+
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, restore_transition_targets)
+            .add_systems(
+                PostUpdate,
+                advance_transitions
+                    .after(resolve_relative_lengths)
+                    .before(UiSystem::Layout),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn assert_color(actual: Color, expected: Color) {
+        let (actual, expected) = (actual.as_rgba_f32(), expected.as_rgba_f32());
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+        }
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        let mut time = app.world.resource_mut::<Time>();
+        let next = time.last_update().unwrap_or(time.startup()) + Duration::from_secs_f32(seconds);
+        time.update_with_instant(next);
+        app.update();
+    }
+
+    #[test]
+    fn transitions_interpolate_over_time() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(PreUpdate, restore_transition_targets)
+            .add_systems(PostUpdate, advance_transitions);
+        let entity = app
+            .world
+            .spawn((
+                Transition::new(1.).properties([
+                    TransitionProperty::BackgroundColor,
+                    TransitionProperty::TextColor,
+                    TransitionProperty::WIDTH,
+                ]),
+                Style {
+                    width: Val::Px(0.),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK),
+                Text::from_sections([
+                    TextSection::new(
+                        "a",
+                        TextStyle {
+                            color: Color::RED,
+                            ..default()
+                        },
+                    ),
+                    TextSection::new(
+                        "b",
+                        TextStyle {
+                            color: Color::BLUE,
+                            ..default()
+                        },
+                    ),
+                ]),
+            ))
+            .id();
+        advance(&mut app, 0.);
+
+        let mut entity_mut = app.world.entity_mut(entity);
+        entity_mut.get_mut::<BackgroundColor>().unwrap().0 = Color::WHITE;
+        entity_mut.get_mut::<Style>().unwrap().width = Val::Px(100.);
+        for section in entity_mut.get_mut::<Text>().unwrap().sections.iter_mut() {
+            section.style.color = Color::BLACK;
+        }
+        advance(&mut app, 0.5);
+
+        let entity_ref = app.world.entity(entity);
+        assert_color(
+            entity_ref.get::<BackgroundColor>().unwrap().0,
+            Color::rgb(0.5, 0.5, 0.5),
+        );
+        assert_eq!(entity_ref.get::<Style>().unwrap().width, Val::Px(50.));
+        let sections = &entity_ref.get::<Text>().unwrap().sections;
+        assert_color(sections[0].style.color, Color::rgb(0.5, 0., 0.));
+        assert_color(sections[1].style.color, Color::rgb(0., 0., 0.5));
+
+        advance(&mut app, 0.5);
+        let entity_ref = app.world.entity(entity);
+        assert_color(entity_ref.get::<BackgroundColor>().unwrap().0, Color::WHITE);
+        assert_eq!(entity_ref.get::<Style>().unwrap().width, Val::Px(100.));
+        assert!(!entity_ref.get::<Transition>().unwrap().is_running());
+    }
+}
@@ -0,0 +1,421 @@
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, ui::UiSystem, window::WindowResized};
+
+pub const DEFAULT_ROOT_FONT_SIZE: f32 = 16.;
+
+#[derive(Clone, Copy, Debug, PartialEq, Default, Reflect)]
+pub enum UiVal {
+    #[default]
+    Auto,
+    Px(f32),
+    Percent(f32),
+    Vw(f32),
+    Vh(f32),
+    VMin(f32),
+    VMax(f32),
+    Em(f32),
+    Rem(f32),
+}
+
+impl UiVal {
+    pub fn is_relative(&self) -> bool {
+        matches!(self, UiVal::Em(_) | UiVal::Rem(_))
+    }
+
+    pub fn resolve(self, em: f32, rem: f32) -> Val {
+        match self {
+            UiVal::Auto => Val::Auto,
+            UiVal::Px(value) => Val::Px(value),
+            UiVal::Percent(value) => Val::Percent(value),
+            UiVal::Vw(value) => Val::Vw(value),
+            UiVal::Vh(value) => Val::Vh(value),
+            UiVal::VMin(value) => Val::VMin(value),
+            UiVal::VMax(value) => Val::VMax(value),
+            UiVal::Em(value) => Val::Px(value * em),
+            UiVal::Rem(value) => Val::Px(value * rem),
+        }
+    }
+}
+
+impl From<Val> for UiVal {
+    fn from(value: Val) -> Self {
+        match value {
+            Val::Auto => UiVal::Auto,
+            Val::Px(value) => UiVal::Px(value),
+            Val::Percent(value) => UiVal::Percent(value),
+            Val::Vw(value) => UiVal::Vw(value),
+            Val::Vh(value) => UiVal::Vh(value),
+            Val::VMin(value) => UiVal::VMin(value),
+            Val::VMax(value) => UiVal::VMax(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct UiValRect {
+    pub left: UiVal,
+    pub right: UiVal,
+    pub top: UiVal,
+    pub bottom: UiVal,
+}
+
+impl UiValRect {
+    pub fn new(left: UiVal, right: UiVal, top: UiVal, bottom: UiVal) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    pub fn all(value: UiVal) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    pub fn axes(horizontal: UiVal, vertical: UiVal) -> Self {
+        Self::new(horizontal, horizontal, vertical, vertical)
+    }
+
+    pub fn horizontal(value: UiVal) -> Self {
+        Self::new(value, value, UiVal::Px(0.), UiVal::Px(0.))
+    }
+
+    pub fn vertical(value: UiVal) -> Self {
+        Self::new(UiVal::Px(0.), UiVal::Px(0.), value, value)
+    }
+}
+
+impl From<UiRect> for UiValRect {
+    fn from(value: UiRect) -> Self {
+        Self::new(
+            value.left.into(),
+            value.right.into(),
+            value.top.into(),
+            value.bottom.into(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum LengthField {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Width,
+    Height,
+    MinWidth,
+    MinHeight,
+    MaxWidth,
+    MaxHeight,
+    FlexBasis,
+    RowGap,
+    ColumnGap,
+    MarginLeft,
+    MarginRight,
+    MarginTop,
+    MarginBottom,
+    PaddingLeft,
+    PaddingRight,
+    PaddingTop,
+    PaddingBottom,
+    BorderLeft,
+    BorderRight,
+    BorderTop,
+    BorderBottom,
+}
+
+impl LengthField {
+    pub const MARGIN: [LengthField; 4] = [
+        LengthField::MarginLeft,
+        LengthField::MarginRight,
+        LengthField::MarginTop,
+        LengthField::MarginBottom,
+    ];
+    pub const PADDING: [LengthField; 4] = [
+        LengthField::PaddingLeft,
+        LengthField::PaddingRight,
+        LengthField::PaddingTop,
+        LengthField::PaddingBottom,
+    ];
+    pub const BORDER: [LengthField; 4] = [
+        LengthField::BorderLeft,
+        LengthField::BorderRight,
+        LengthField::BorderTop,
+        LengthField::BorderBottom,
+    ];
+
+    pub fn get(self, style: &Style) -> Val {
+        match self {
+            LengthField::Left => style.left,
+            LengthField::Right => style.right,
+            LengthField::Top => style.top,
+            LengthField::Bottom => style.bottom,
+            LengthField::Width => style.width,
+            LengthField::Height => style.height,
+            LengthField::MinWidth => style.min_width,
+            LengthField::MinHeight => style.min_height,
+            LengthField::MaxWidth => style.max_width,
+            LengthField::MaxHeight => style.max_height,
+            LengthField::FlexBasis => style.flex_basis,
+            LengthField::RowGap => style.row_gap,
+            LengthField::ColumnGap => style.column_gap,
+            LengthField::MarginLeft => style.margin.left,
+            LengthField::MarginRight => style.margin.right,
+            LengthField::MarginTop => style.margin.top,
+            LengthField::MarginBottom => style.margin.bottom,
+            LengthField::PaddingLeft => style.padding.left,
+            LengthField::PaddingRight => style.padding.right,
+            LengthField::PaddingTop => style.padding.top,
+            LengthField::PaddingBottom => style.padding.bottom,
+            LengthField::BorderLeft => style.border.left,
+            LengthField::BorderRight => style.border.right,
+            LengthField::BorderTop => style.border.top,
+            LengthField::BorderBottom => style.border.bottom,
+        }
+    }
+
+    pub fn get_mut(self, style: &mut Style) -> &mut Val {
+        match self {
+            LengthField::Left => &mut style.left,
+            LengthField::Right => &mut style.right,
+            LengthField::Top => &mut style.top,
+            LengthField::Bottom => &mut style.bottom,
+            LengthField::Width => &mut style.width,
+            LengthField::Height => &mut style.height,
+            LengthField::MinWidth => &mut style.min_width,
+            LengthField::MinHeight => &mut style.min_height,
+            LengthField::MaxWidth => &mut style.max_width,
+            LengthField::MaxHeight => &mut style.max_height,
+            LengthField::FlexBasis => &mut style.flex_basis,
+            LengthField::RowGap => &mut style.row_gap,
+            LengthField::ColumnGap => &mut style.column_gap,
+            LengthField::MarginLeft => &mut style.margin.left,
+            LengthField::MarginRight => &mut style.margin.right,
+            LengthField::MarginTop => &mut style.margin.top,
+            LengthField::MarginBottom => &mut style.margin.bottom,
+            LengthField::PaddingLeft => &mut style.padding.left,
+            LengthField::PaddingRight => &mut style.padding.right,
+            LengthField::PaddingTop => &mut style.padding.top,
+            LengthField::PaddingBottom => &mut style.padding.bottom,
+            LengthField::BorderLeft => &mut style.border.left,
+            LengthField::BorderRight => &mut style.border.right,
+            LengthField::BorderTop => &mut style.border.top,
+            LengthField::BorderBottom => &mut style.border.bottom,
+        }
+    }
+}
+
+/// Relative lengths of a node, with the value each one last resolved to. A resolved field whose
+/// `Style` value was since written by something else is dropped instead of overwritten.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct UiLengths(Vec<(LengthField, UiVal, Option<Val>)>);
+
+impl UiLengths {
+    pub fn get(&self, field: LengthField) -> Option<UiVal> {
+        self.0
+            .iter()
+            .find(|(existing, _, _)| *existing == field)
+            .map(|(_, value, _)| *value)
+    }
+
+    pub fn set(&mut self, field: LengthField, value: UiVal) {
+        self.0.retain(|(existing, _, _)| *existing != field);
+        if value.is_relative() {
+            self.0.push((field, value, None));
+        }
+    }
+
+    pub(crate) fn restore(&mut self, field: LengthField, value: Option<UiVal>) {
+        self.0.retain(|(existing, _, _)| *existing != field);
+        self.0.extend(value.map(|value| (field, value, None)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (LengthField, UiVal)> + '_ {
+        self.0.iter().map(|(field, value, _)| (*field, *value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct RootFontSize(pub f32);
+
+impl Default for RootFontSize {
+    fn default() -> Self {
+        Self(DEFAULT_ROOT_FONT_SIZE)
+    }
+}
+
+/// Font size `Em` lengths resolve against on this node and its descendants, the way a text
+/// node's own font size applies to it and its children.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct FontSize(pub f32);
+
+type FontSizeSource<'a> = (Option<&'a FontSize>, Option<&'a Text>);
+
+fn inherited_font_size(
+    entity: Entity,
+    sources: &Query<FontSizeSource>,
+    parents: &Query<&Parent>,
+) -> Option<f32> {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find_map(|entity| {
+            let (size, text) = sources.get(entity).ok()?;
+            size.map(|size| size.0).or_else(|| {
+                text.and_then(|text| text.sections.first())
+                    .map(|section| section.style.font_size)
+            })
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve_relative_lengths(
+    root_font_size: Res<RootFontSize>,
+    ui_scale: Res<UiScale>,
+    mut resized: EventReader<WindowResized>,
+    changed_lengths: Query<Entity, Changed<UiLengths>>,
+    changed_sizes: Query<Entity, Or<(Changed<FontSize>, Changed<Text>, Changed<Parent>)>>,
+    mut removed_sizes: RemovedComponents<FontSize>,
+    children: Query<&Children>,
+    sources: Query<FontSizeSource>,
+    parents: Query<&Parent>,
+    mut nodes: Query<(Entity, &mut UiLengths, &mut Style)>,
+) {
+    let resolve_all =
+        root_font_size.is_changed() || ui_scale.is_changed() || resized.iter().last().is_some();
+    let all: Vec<Entity> = if resolve_all {
+        nodes.iter().map(|(entity, _, _)| entity).collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut resolve = |entity: Entity| {
+        let Ok((entity, mut lengths, mut style)) = nodes.get_mut(entity) else {
+            return;
+        };
+        if lengths.is_empty() {
+            return;
+        }
+        let em = inherited_font_size(entity, &sources, &parents).unwrap_or(root_font_size.0);
+        lengths
+            .bypass_change_detection()
+            .0
+            .retain_mut(|(field, value, resolved)| {
+                let current = field.get(&style);
+                if resolved.is_some_and(|resolved| resolved != current) {
+                    return false;
+                }
+                let next = value.resolve(em, root_font_size.0);
+                if current != next {
+                    *field.get_mut(&mut style) = next;
+                }
+                *resolved = Some(next);
+                true
+            });
+    };
+
+    if resolve_all {
+        all.into_iter().for_each(resolve);
+        return;
+    }
+
+    for entity in changed_lengths.iter() {
+        resolve(entity);
+    }
+    for entity in changed_sizes.iter().chain(removed_sizes.iter()) {
+        resolve(entity);
+        for descendant in children.iter_descendants(entity) {
+            resolve(descendant);
+        }
+    }
+}
+
+pub struct RelativeUnitsPlugin;
+
+impl Plugin for RelativeUnitsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RootFontSize>()
+            .register_type::<UiLengths>()
+            .add_systems(
+                PostUpdate,
+                resolve_relative_lengths.before(UiSystem::Layout),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn em_width(em: f32) -> UiLengths {
+        let mut lengths = UiLengths::default();
+        lengths.set(LengthField::Width, UiVal::Em(em));
+        lengths
+    }
+
+    fn width(app: &App, entity: Entity) -> Val {
+        app.world.get::<Style>(entity).unwrap().width
+    }
+
+    #[test]
+    fn em_follows_the_nearest_font_size() {
+        let mut app = App::new();
+        app.init_resource::<UiScale>()
+            .add_event::<WindowResized>()
+            .add_plugins(RelativeUnitsPlugin);
+        let mut child = Entity::PLACEHOLDER;
+        let mut grandchild = Entity::PLACEHOLDER;
+        let parent = app
+            .world
+            .spawn((Style::default(), FontSize(20.)))
+            .with_children(|parent| {
+                child = parent
+                    .spawn((Style::default(), em_width(2.)))
+                    .with_children(|child| {
+                        grandchild = child.spawn((Style::default(), em_width(0.5))).id();
+                    })
+                    .id();
+            })
+            .id();
+        let orphan = app.world.spawn((Style::default(), em_width(2.))).id();
+        app.update();
+
+        assert_eq!(width(&app, child), Val::Px(40.));
+        assert_eq!(width(&app, grandchild), Val::Px(10.));
+        assert_eq!(width(&app, orphan), Val::Px(2. * DEFAULT_ROOT_FONT_SIZE));
+
+        app.world.get_mut::<FontSize>(parent).unwrap().0 = 10.;
+        app.update();
+        assert_eq!(width(&app, child), Val::Px(20.));
+        assert_eq!(width(&app, grandchild), Val::Px(5.));
+
+        app.world.entity_mut(parent).remove::<FontSize>();
+        app.update();
+        assert_eq!(
+            width(&app, grandchild),
+            Val::Px(0.5 * DEFAULT_ROOT_FONT_SIZE)
+        );
+    }
+
+    #[test]
+    fn overwritten_lengths_are_not_resolved_again() {
+        let mut app = App::new();
+        app.init_resource::<UiScale>()
+            .add_event::<WindowResized>()
+            .add_plugins(RelativeUnitsPlugin);
+        let node = app.world.spawn((Style::default(), em_width(2.))).id();
+        app.update();
+        assert_eq!(width(&app, node), Val::Px(32.));
+
+        app.world.get_mut::<Style>(node).unwrap().width = Val::Px(100.);
+        app.world.resource_mut::<RootFontSize>().0 = 20.;
+        app.update();
+        assert_eq!(width(&app, node), Val::Px(100.));
+        assert!(app.world.get::<UiLengths>(node).unwrap().is_empty());
+    }
+}
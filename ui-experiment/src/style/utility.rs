@@ -0,0 +1,486 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::prelude::*;
+
+use crate::{
+    parse_color, parse_val, InternalUiSpawner, LengthField, StyleDeclaration, StyleLayer,
+    StyleLayers, Styler, Theme, UiBundleGenerator, UiBundleGeneratorStyler, UiComponent,
+    UiStyleSet, UiVal,
+};
+
+const FONT_SIZES: [(&str, f32); 9] = [
+    ("xs", 12.),
+    ("sm", 14.),
+    ("base", 16.),
+    ("lg", 18.),
+    ("xl", 20.),
+    ("2xl", 24.),
+    ("3xl", 30.),
+    ("4xl", 36.),
+    ("5xl", 48.),
+];
+
+const PALETTE: [(&str, &str); 60] = [
+    ("slate-50", "f8fafc"),
+    ("slate-100", "f1f5f9"),
+    ("slate-200", "e2e8f0"),
+    ("slate-300", "cbd5e1"),
+    ("slate-400", "94a3b8"),
+    ("slate-500", "64748b"),
+    ("slate-600", "475569"),
+    ("slate-700", "334155"),
+    ("slate-800", "1e293b"),
+    ("slate-900", "0f172a"),
+    ("gray-50", "f9fafb"),
+    ("gray-100", "f3f4f6"),
+    ("gray-200", "e5e7eb"),
+    ("gray-300", "d1d5db"),
+    ("gray-400", "9ca3af"),
+    ("gray-500", "6b7280"),
+    ("gray-600", "4b5563"),
+    ("gray-700", "374151"),
+    ("gray-800", "1f2937"),
+    ("gray-900", "111827"),
+    ("red-50", "fef2f2"),
+    ("red-100", "fee2e2"),
+    ("red-200", "fecaca"),
+    ("red-300", "fca5a5"),
+    ("red-400", "f87171"),
+    ("red-500", "ef4444"),
+    ("red-600", "dc2626"),
+    ("red-700", "b91c1c"),
+    ("red-800", "991b1b"),
+    ("red-900", "7f1d1d"),
+    ("amber-50", "fffbeb"),
+    ("amber-100", "fef3c7"),
+    ("amber-200", "fde68a"),
+    ("amber-300", "fcd34d"),
+    ("amber-400", "fbbf24"),
+    ("amber-500", "f59e0b"),
+    ("amber-600", "d97706"),
+    ("amber-700", "b45309"),
+    ("amber-800", "92400e"),
+    ("amber-900", "78350f"),
+    ("green-50", "f0fdf4"),
+    ("green-100", "dcfce7"),
+    ("green-200", "bbf7d0"),
+    ("green-300", "86efac"),
+    ("green-400", "4ade80"),
+    ("green-500", "22c55e"),
+    ("green-600", "16a34a"),
+    ("green-700", "15803d"),
+    ("green-800", "166534"),
+    ("green-900", "14532d"),
+    ("blue-50", "eff6ff"),
+    ("blue-100", "dbeafe"),
+    ("blue-200", "bfdbfe"),
+    ("blue-300", "93c5fd"),
+    ("blue-400", "60a5fa"),
+    ("blue-500", "3b82f6"),
+    ("blue-600", "2563eb"),
+    ("blue-700", "1d4ed8"),
+    ("blue-800", "1e40af"),
+    ("blue-900", "1e3a8a"),
+];
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct UtilityClasses(pub String);
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn tw(self, classes: impl Into<String>) -> Self {
+        self.insert(UtilityClasses(classes.into()))
+    }
+}
+
+fn spacing(value: &str, theme: &Theme) -> Option<UiVal> {
+    if let Some(arbitrary) = value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+    {
+        return parse_val(arbitrary).ok();
+    }
+    match value {
+        "auto" => return Some(UiVal::Auto),
+        "px" => return Some(UiVal::Px(1.)),
+        _ => {}
+    }
+    let step: f32 = value.parse().ok()?;
+    if theme.spacing.is_empty() || step.fract() != 0. {
+        Some(UiVal::Px(step * 4.))
+    } else {
+        Some(UiVal::Px(theme.space(step as usize)))
+    }
+}
+
+fn size(value: &str, theme: &Theme) -> Option<UiVal> {
+    if value == "full" {
+        return Some(UiVal::Percent(100.));
+    }
+    if let Some((numerator, denominator)) = value.split_once('/') {
+        let numerator: f32 = numerator.parse().ok()?;
+        let denominator: f32 = denominator.parse().ok()?;
+        return Some(UiVal::Percent(numerator / denominator * 100.));
+    }
+    spacing(value, theme)
+}
+
+fn color(value: &str, theme: &Theme) -> Option<Color> {
+    if let Some(arbitrary) = value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+    {
+        return parse_color(arbitrary).ok();
+    }
+    theme
+        .colors
+        .get(value)
+        .copied()
+        .or_else(|| {
+            PALETTE
+                .iter()
+                .find(|(name, _)| *name == value)
+                .and_then(|(_, hex)| Color::hex(hex).ok())
+        })
+        .or_else(|| parse_color(value).ok())
+}
+
+fn font_size(value: &str, theme: &Theme) -> Option<f32> {
+    theme.font_sizes.get(value).copied().or_else(|| {
+        FONT_SIZES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, size)| *size)
+    })
+}
+
+fn lengths(fields: &[LengthField], val: UiVal) -> Vec<StyleDeclaration> {
+    fields
+        .iter()
+        .map(|field| StyleDeclaration::Length(*field, val))
+        .collect()
+}
+
+fn spacing_utility(prefix: &str, value: &str, theme: &Theme) -> Option<Vec<StyleDeclaration>> {
+    use LengthField::*;
+    let fields: &[LengthField] = match prefix {
+        "p" => &LengthField::PADDING,
+        "px" => &[PaddingLeft, PaddingRight],
+        "py" => &[PaddingTop, PaddingBottom],
+        "pt" => &[PaddingTop],
+        "pr" => &[PaddingRight],
+        "pb" => &[PaddingBottom],
+        "pl" => &[PaddingLeft],
+        "m" => &LengthField::MARGIN,
+        "mx" => &[MarginLeft, MarginRight],
+        "my" => &[MarginTop, MarginBottom],
+        "mt" => &[MarginTop],
+        "mr" => &[MarginRight],
+        "mb" => &[MarginBottom],
+        "ml" => &[MarginLeft],
+        "gap" => &[RowGap, ColumnGap],
+        "gap-x" => &[ColumnGap],
+        "gap-y" => &[RowGap],
+        "inset" => &[Left, Right, Top, Bottom],
+        "top" => &[Top],
+        "right" => &[Right],
+        "bottom" => &[Bottom],
+        "left" => &[Left],
+        "basis" => &[FlexBasis],
+        "w" | "min-w" | "max-w" | "h" | "min-h" | "max-h" => {
+            let val = match value {
+                "screen" if prefix.ends_with('w') => UiVal::Vw(100.),
+                "screen" => UiVal::Vh(100.),
+                _ => size(value, theme)?,
+            };
+            let field = match prefix {
+                "w" => Width,
+                "min-w" => MinWidth,
+                "max-w" => MaxWidth,
+                "h" => Height,
+                "min-h" => MinHeight,
+                _ => MaxHeight,
+            };
+            return Some(lengths(&[field], val));
+        }
+        _ => return None,
+    };
+    Some(lengths(fields, spacing(value, theme)?))
+}
+
+pub fn parse_utility(token: &str, theme: &Theme) -> Option<Vec<StyleDeclaration>> {
+    let single = |declaration| Some(vec![declaration]);
+    let declaration = match token {
+        "flex" => StyleDeclaration::Display(Display::Flex),
+        "grid" => StyleDeclaration::Display(Display::Grid),
+        "hidden" => StyleDeclaration::Display(Display::None),
+        "visible" => StyleDeclaration::Visibility(Visibility::Visible),
+        "invisible" => StyleDeclaration::Visibility(Visibility::Hidden),
+        "relative" => StyleDeclaration::PositionType(PositionType::Relative),
+        "absolute" => StyleDeclaration::PositionType(PositionType::Absolute),
+        "flex-row" => StyleDeclaration::FlexDirection(FlexDirection::Row),
+        "flex-col" => StyleDeclaration::FlexDirection(FlexDirection::Column),
+        "flex-row-reverse" => StyleDeclaration::FlexDirection(FlexDirection::RowReverse),
+        "flex-col-reverse" => StyleDeclaration::FlexDirection(FlexDirection::ColumnReverse),
+        "flex-wrap" => StyleDeclaration::FlexWrap(FlexWrap::Wrap),
+        "flex-wrap-reverse" => StyleDeclaration::FlexWrap(FlexWrap::WrapReverse),
+        "flex-nowrap" => StyleDeclaration::FlexWrap(FlexWrap::NoWrap),
+        "grow" => StyleDeclaration::FlexGrow(1.),
+        "grow-0" => StyleDeclaration::FlexGrow(0.),
+        "shrink" => StyleDeclaration::FlexShrink(1.),
+        "shrink-0" => StyleDeclaration::FlexShrink(0.),
+        "items-start" => StyleDeclaration::AlignItems(AlignItems::FlexStart),
+        "items-end" => StyleDeclaration::AlignItems(AlignItems::FlexEnd),
+        "items-center" => StyleDeclaration::AlignItems(AlignItems::Center),
+        "items-baseline" => StyleDeclaration::AlignItems(AlignItems::Baseline),
+        "items-stretch" => StyleDeclaration::AlignItems(AlignItems::Stretch),
+        "self-auto" => StyleDeclaration::AlignSelf(AlignSelf::Auto),
+        "self-start" => StyleDeclaration::AlignSelf(AlignSelf::FlexStart),
+        "self-end" => StyleDeclaration::AlignSelf(AlignSelf::FlexEnd),
+        "self-center" => StyleDeclaration::AlignSelf(AlignSelf::Center),
+        "self-stretch" => StyleDeclaration::AlignSelf(AlignSelf::Stretch),
+        "justify-start" => StyleDeclaration::JustifyContent(JustifyContent::FlexStart),
+        "justify-end" => StyleDeclaration::JustifyContent(JustifyContent::FlexEnd),
+        "justify-center" => StyleDeclaration::JustifyContent(JustifyContent::Center),
+        "justify-between" => StyleDeclaration::JustifyContent(JustifyContent::SpaceBetween),
+        "justify-around" => StyleDeclaration::JustifyContent(JustifyContent::SpaceAround),
+        "justify-evenly" => StyleDeclaration::JustifyContent(JustifyContent::SpaceEvenly),
+        "content-start" => StyleDeclaration::AlignContent(AlignContent::FlexStart),
+        "content-end" => StyleDeclaration::AlignContent(AlignContent::FlexEnd),
+        "content-center" => StyleDeclaration::AlignContent(AlignContent::Center),
+        "content-between" => StyleDeclaration::AlignContent(AlignContent::SpaceBetween),
+        "content-around" => StyleDeclaration::AlignContent(AlignContent::SpaceAround),
+        "content-evenly" => StyleDeclaration::AlignContent(AlignContent::SpaceEvenly),
+        "overflow-visible" => StyleDeclaration::Overflow(Overflow::visible()),
+        "overflow-hidden" | "overflow-clip" => StyleDeclaration::Overflow(Overflow::clip()),
+        "overflow-x-hidden" | "overflow-x-clip" => StyleDeclaration::Overflow(Overflow::clip_x()),
+        "overflow-y-hidden" | "overflow-y-clip" => StyleDeclaration::Overflow(Overflow::clip_y()),
+        "aspect-square" => StyleDeclaration::AspectRatio(Some(1.)),
+        "aspect-video" => StyleDeclaration::AspectRatio(Some(16. / 9.)),
+        "aspect-auto" => StyleDeclaration::AspectRatio(None),
+        "text-left" => StyleDeclaration::TextAlignment(TextAlignment::Left),
+        "text-center" => StyleDeclaration::TextAlignment(TextAlignment::Center),
+        "text-right" => StyleDeclaration::TextAlignment(TextAlignment::Right),
+        "border" => return Some(lengths(&LengthField::BORDER, UiVal::Px(1.))),
+        _ => return parse_prefixed_utility(token, theme),
+    };
+    single(declaration)
+}
+
+fn parse_prefixed_utility(token: &str, theme: &Theme) -> Option<Vec<StyleDeclaration>> {
+    if let Some(declarations) = token
+        .rsplit_once('-')
+        .and_then(|(prefix, value)| spacing_utility(prefix, value, theme))
+    {
+        return Some(declarations);
+    }
+    parse_named_utility(token, theme)
+}
+
+fn parse_named_utility(token: &str, theme: &Theme) -> Option<Vec<StyleDeclaration>> {
+    let (prefix, value) = token.split_once('-')?;
+    let declaration = match prefix {
+        "bg" => StyleDeclaration::BackgroundColor(color(value, theme)?),
+        "text" => match font_size(value, theme) {
+            Some(size) => StyleDeclaration::FontSize(size),
+            None => StyleDeclaration::TextColor(color(value, theme)?),
+        },
+        "border" => {
+            return Some(lengths(
+                &LengthField::BORDER,
+                UiVal::Px(value.parse().ok()?),
+            ))
+        }
+        "z" => StyleDeclaration::ZIndex(ZIndex::Local(value.parse().ok()?)),
+        "grow" => StyleDeclaration::FlexGrow(value.parse().ok()?),
+        "shrink" => StyleDeclaration::FlexShrink(value.parse().ok()?),
+        _ => return None,
+    };
+    Some(vec![declaration])
+}
+
+pub fn parse_utilities<'a>(
+    source: &'a str,
+    theme: &Theme,
+) -> (Vec<StyleDeclaration>, Vec<&'a str>) {
+    let mut declarations = Vec::new();
+    let mut unknown = Vec::new();
+    for token in source.split_whitespace() {
+        match parse_utility(token, theme) {
+            Some(parsed) => declarations.extend(parsed),
+            None => unknown.push(token),
+        }
+    }
+    (declarations, unknown)
+}
+
+fn invalidate_utility_classes(
+    theme: Res<Theme>,
+    mut removed: RemovedComponents<UtilityClasses>,
+    mut nodes: Query<(Entity, Option<Ref<UtilityClasses>>, &mut StyleLayers)>,
+) {
+    let restyle_all = theme.is_changed();
+    for (entity, classes, mut layers) in nodes.iter_mut() {
+        let Some(classes) = classes else {
+            continue;
+        };
+        if classes.is_changed() {
+            let (_, unknown) = parse_utilities(&classes.0, &theme);
+            for token in unknown {
+                warn!(
+                    "unknown utility class `{token}` on {entity:?} in \"{}\"",
+                    classes.0
+                );
+            }
+        }
+        if restyle_all || classes.is_changed() {
+            layers.invalidate(StyleLayer::Utilities);
+        }
+    }
+    for entity in removed.iter() {
+        if let Ok((_, _, mut layers)) = nodes.get_mut(entity) {
+            layers.invalidate(StyleLayer::Utilities);
+        }
+    }
+}
+
+pub struct UtilityClassesPlugin;
+
+impl Plugin for UtilityClassesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            invalidate_utility_classes.in_set(UiStyleSet::Utilities),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed_lengths(source: &str, theme: &Theme) -> Vec<(LengthField, UiVal)> {
+        parse_utilities(source, theme)
+            .0
+            .into_iter()
+            .filter_map(|declaration| match declaration {
+                StyleDeclaration::Length(field, val) => Some((field, val)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn parsed_color(token: &str, theme: &Theme) -> Option<Color> {
+        match parse_utility(token, theme)?.as_slice() {
+            [StyleDeclaration::BackgroundColor(color) | StyleDeclaration::TextColor(color)] => {
+                Some(*color)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn layout_tokens_map_to_declarations_in_order() {
+        let (declarations, unknown) =
+            parse_utilities("flex  flex-col\nitems-center hidden", &Theme::default());
+        assert!(unknown.is_empty());
+        assert!(matches!(
+            declarations.as_slice(),
+            [
+                StyleDeclaration::Display(Display::Flex),
+                StyleDeclaration::FlexDirection(FlexDirection::Column),
+                StyleDeclaration::AlignItems(AlignItems::Center),
+                StyleDeclaration::Display(Display::None),
+            ]
+        ));
+    }
+
+    #[test]
+    fn spacing_and_sizes_resolve_against_the_theme() {
+        use LengthField::*;
+        let theme = Theme::default();
+        assert_eq!(
+            parsed_lengths("px-4 mt-1.5 w-1/2 h-screen gap-x-2", &theme),
+            [
+                (PaddingLeft, UiVal::Px(16.)),
+                (PaddingRight, UiVal::Px(16.)),
+                (MarginTop, UiVal::Px(6.)),
+                (Width, UiVal::Percent(50.)),
+                (Height, UiVal::Vh(100.)),
+                (ColumnGap, UiVal::Px(8.)),
+            ]
+        );
+        assert_eq!(
+            parsed_lengths("mx-auto min-w-[3em] border-2", &theme),
+            [
+                (MarginLeft, UiVal::Auto),
+                (MarginRight, UiVal::Auto),
+                (MinWidth, UiVal::Em(3.)),
+                (BorderLeft, UiVal::Px(2.)),
+                (BorderRight, UiVal::Px(2.)),
+                (BorderTop, UiVal::Px(2.)),
+                (BorderBottom, UiVal::Px(2.)),
+            ]
+        );
+
+        let theme = Theme::default().with_spacing([0., 2., 4., 8.]);
+        assert_eq!(
+            parsed_lengths("pt-3 pb-9", &theme),
+            [(PaddingTop, UiVal::Px(8.)), (PaddingBottom, UiVal::Px(8.))]
+        );
+    }
+
+    #[test]
+    fn colors_and_font_sizes_prefer_theme_tokens() {
+        let theme = Theme::default()
+            .with_color("brand", Color::ORANGE)
+            .with_color("red-500", Color::PURPLE)
+            .with_font_size("lg", 22.);
+        assert_eq!(parsed_color("bg-brand", &theme), Some(Color::ORANGE));
+        assert_eq!(parsed_color("text-red-500", &theme), Some(Color::PURPLE));
+        assert_eq!(
+            parsed_color("bg-red-500", &Theme::default()),
+            Color::hex("ef4444").ok()
+        );
+        assert_eq!(
+            parsed_color("bg-[#00ff00]", &theme),
+            Some(Color::rgb(0., 1., 0.))
+        );
+        assert!(matches!(
+            parse_utility("text-lg", &theme).as_deref(),
+            Some([StyleDeclaration::FontSize(size)]) if *size == 22.
+        ));
+        assert!(matches!(
+            parse_utility("text-lg", &Theme::default()).as_deref(),
+            Some([StyleDeclaration::FontSize(size)]) if *size == 18.
+        ));
+    }
+
+    #[test]
+    fn unknown_tokens_are_reported_and_skipped() {
+        let (declarations, unknown) = parse_utilities(
+            "grow bogus p-x bg-nope text-unknown w-[oops] grow-1",
+            &Theme::default(),
+        );
+        assert_eq!(
+            unknown,
+            ["bogus", "p-x", "bg-nope", "text-unknown", "w-[oops]"]
+        );
+        assert!(matches!(
+            declarations.as_slice(),
+            [
+                StyleDeclaration::FlexGrow(first),
+                StyleDeclaration::FlexGrow(second),
+            ] if *first == 1. && *second == 1.
+        ));
+    }
+}
@@ -0,0 +1,349 @@
+use std::any::TypeId;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    InternalUiSpawner, NodeComponentsMut, StyleLayer, StyleLayers, Styler, TypedStyler,
+    UiBundleGenerator, UiBundleGeneratorStyler, UiComponent, UiStyleSet,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ViewportClass {
+    Compact,
+    #[default]
+    Regular,
+    Wide,
+}
+
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ViewportBreakpoints {
+    pub compact: f32,
+    pub wide: f32,
+}
+
+impl Default for ViewportBreakpoints {
+    fn default() -> Self {
+        Self {
+            compact: 600.,
+            wide: 1200.,
+        }
+    }
+}
+
+impl ViewportBreakpoints {
+    pub fn classify(&self, width: f32) -> ViewportClass {
+        if width < self.compact {
+            ViewportClass::Compact
+        } else if width >= self.wide {
+            ViewportClass::Wide
+        } else {
+            ViewportClass::Regular
+        }
+    }
+}
+
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Viewport {
+    pub width: f32,
+    pub height: f32,
+    pub scale: f64,
+    pub class: ViewportClass,
+}
+
+impl Viewport {
+    pub fn aspect_ratio(&self) -> f32 {
+        if self.height > 0. {
+            self.width / self.height
+        } else {
+            0.
+        }
+    }
+
+    pub fn is_portrait(&self) -> bool {
+        self.height > self.width
+    }
+}
+
+pub trait ViewportInput: Clone + PartialEq + Send + Sync + 'static {
+    fn from_viewport(viewport: &Viewport) -> Self;
+}
+
+impl ViewportInput for ViewportClass {
+    fn from_viewport(viewport: &Viewport) -> Self {
+        viewport.class
+    }
+}
+
+impl ViewportInput for Viewport {
+    fn from_viewport(viewport: &Viewport) -> Self {
+        *viewport
+    }
+}
+
+#[derive(Component)]
+pub struct ViewportStyled<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput> {
+    pub styler: Arc<S>,
+    applied: Option<I>,
+}
+
+impl<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput> ViewportStyled<S, I> {
+    pub fn new(styler: S) -> Self {
+        Self {
+            styler: Arc::new(styler),
+            applied: None,
+        }
+    }
+
+    pub fn reapply(&mut self) {
+        self.applied = None;
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn viewport_style<I: ViewportInput, V: TypedStyler<I> + Send + Sync + 'static>(
+        self,
+        styler: V,
+    ) -> Self {
+        self.insert(ViewportStyled::<V, I>::new(styler))
+    }
+}
+
+pub(crate) fn update_viewport(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<UiScale>>,
+    breakpoints: Res<ViewportBreakpoints>,
+    mut viewport: ResMut<Viewport>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let scale = ui_scale.map_or(1., |ui_scale| ui_scale.scale);
+    let width = window.width() / scale as f32;
+    let height = window.height() / scale as f32;
+    let next = Viewport {
+        width,
+        height,
+        scale,
+        class: breakpoints.classify(width),
+    };
+    if *viewport != next {
+        *viewport = next;
+    }
+}
+
+trait ViewportRestyle: Send + Sync + 'static {
+    fn restyle<'a>(
+        &self,
+        node: NodeComponentsMut<'a>,
+        text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>);
+}
+
+struct AppliedViewportStyler<S, I> {
+    styler: Arc<S>,
+    input: I,
+}
+
+impl<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput> ViewportRestyle
+    for AppliedViewportStyler<S, I>
+{
+    fn restyle<'a>(
+        &self,
+        node: NodeComponentsMut<'a>,
+        text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>) {
+        let text = text.map(|text| self.styler.typed_text_style(text, self.input.clone()));
+        (self.styler.typed_style(node, self.input.clone()), text)
+    }
+}
+
+/// The typed stylers an entity's viewport layer reapplies, each with the input it last saw.
+#[derive(Clone, Default)]
+pub(crate) struct ViewportStylers(Vec<(TypeId, Arc<dyn ViewportRestyle>)>);
+
+impl ViewportStylers {
+    fn set<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput>(
+        &mut self,
+        styler: Arc<S>,
+        input: I,
+    ) {
+        let restyle = Arc::new(AppliedViewportStyler { styler, input });
+        let type_id = TypeId::of::<AppliedViewportStyler<S, I>>();
+        match self.0.iter_mut().find(|(existing, _)| *existing == type_id) {
+            Some((_, existing)) => *existing = restyle,
+            None => self.0.push((type_id, restyle)),
+        }
+    }
+
+    pub(crate) fn restyle<'a>(
+        &self,
+        mut node: NodeComponentsMut<'a>,
+        mut text: Option<Mut<'a, Text>>,
+    ) -> (NodeComponentsMut<'a>, Option<Mut<'a, Text>>) {
+        for (_, restyle) in self.0.iter() {
+            (node, text) = restyle.restyle(node, text);
+        }
+        (node, text)
+    }
+}
+
+fn apply_viewport_stylers<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput>(
+    viewport: Res<Viewport>,
+    mut nodes: Query<(&mut ViewportStyled<S, I>, &mut StyleLayers)>,
+) {
+    let input = I::from_viewport(&viewport);
+    for (mut styled, mut layers) in nodes.iter_mut() {
+        if styled.applied.as_ref() == Some(&input) {
+            continue;
+        }
+        layers.viewport.set(styled.styler.clone(), input.clone());
+        layers.invalidate(StyleLayer::Viewport);
+        styled.applied = Some(input.clone());
+    }
+}
+
+pub struct ViewportPlugin;
+
+impl Plugin for ViewportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ViewportBreakpoints>()
+            .init_resource::<Viewport>()
+            .add_systems(PreUpdate, update_viewport);
+    }
+}
+
+pub struct ViewportStylerPlugin<S, I = ViewportClass> {
+    phantom: PhantomData<fn() -> (S, I)>,
+}
+
+impl<S, I> Default for ViewportStylerPlugin<S, I> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: TypedStyler<I> + Send + Sync + 'static, I: ViewportInput> Plugin
+    for ViewportStylerPlugin<S, I>
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_viewport_stylers::<S, I>.in_set(UiStyleSet::Viewport),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::window::WindowResolution;
+
+    use super::*;
+    use crate::{
+        BgColor, FocusPolicyApplier, Layout, StyleLayersPlugin, TextApplier, TextStyling,
+        UiLengths, VisibilityApplier, ZIndexApplier,
+    };
+
+    struct CompactWidth;
+
+    impl TypedStyler<ViewportClass> for CompactWidth {
+        fn typed_text_section_style<T: TextStyling>(&self, styled: T, _: ViewportClass) -> T {
+            styled
+        }
+
+        fn typed_text_style<T: TextApplier>(&self, styled: T, _: ViewportClass) -> T {
+            styled
+        }
+
+        fn typed_style<
+            T: Layout + VisibilityApplier + BgColor + FocusPolicyApplier + ZIndexApplier,
+        >(
+            &self,
+            styled: T,
+            class: ViewportClass,
+        ) -> T {
+            match class {
+                ViewportClass::Compact => styled.width(Val::Percent(100.)),
+                _ => styled.width(Val::Px(400.)),
+            }
+        }
+    }
+
+    fn resize(app: &mut App, width: f32, height: f32) {
+        let mut windows = app
+            .world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>();
+        windows
+            .single_mut(&mut app.world)
+            .resolution
+            .set(width, height);
+    }
+
+    #[test]
+    fn viewport_class_follows_window_resolution() {
+        let mut app = App::new();
+        app.add_plugins((
+            StyleLayersPlugin,
+            ViewportPlugin,
+            ViewportStylerPlugin::<CompactWidth>::default(),
+        ));
+        app.world.spawn((
+            Window {
+                resolution: WindowResolution::new(480., 800.),
+                ..default()
+            },
+            PrimaryWindow,
+        ));
+        let node = app
+            .world
+            .spawn((
+                NodeBundle::default(),
+                UiLengths::default(),
+                StyleLayers::default(),
+                ViewportStyled::<CompactWidth, ViewportClass>::new(CompactWidth),
+            ))
+            .id();
+
+        app.update();
+        let viewport = *app.world.resource::<Viewport>();
+        assert_eq!(viewport.class, ViewportClass::Compact);
+        assert!(viewport.is_portrait());
+        assert_eq!(
+            app.world.get::<Style>(node).unwrap().width,
+            Val::Percent(100.)
+        );
+
+        resize(&mut app, 1600., 900.);
+        app.update();
+        assert_eq!(app.world.resource::<Viewport>().class, ViewportClass::Wide);
+        assert_eq!(app.world.get::<Style>(node).unwrap().width, Val::Px(400.));
+
+        app.world
+            .get_mut::<StyleLayers>(node)
+            .unwrap()
+            .invalidate(StyleLayer::Base);
+        app.update();
+        assert_eq!(app.world.get::<Style>(node).unwrap().width, Val::Px(400.));
+
+        resize(&mut app, 800., 600.);
+        app.update();
+        assert_eq!(
+            app.world.resource::<Viewport>().class,
+            ViewportClass::Regular
+        );
+    }
+}
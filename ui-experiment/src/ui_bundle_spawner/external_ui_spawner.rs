@@ -63,9 +63,9 @@ pub trait ExternalUiSpawner<'w, 's, St: Styler> {
 
     fn button<'a>(
         &'a mut self,
-    ) -> UiComponent<'w, 's, 'a, ButtonBundle, Self::InternalSpawner, St, usize> {
+    ) -> UiComponent<'w, 's, 'a, UiButtonBundle, Self::InternalSpawner, St, usize> {
         let styler = self.get_styler();
-        UiComponent::new(ButtonBundle::default(), self.get_spawner(), styler)
+        UiComponent::new(UiButtonBundle::default(), self.get_spawner(), styler)
     }
 }
 
@@ -1,9 +1,15 @@
 use bevy::ecs::system::EntityCommands;
+use bevy::prelude::Bundle;
 
+use crate::capture_spawned_style;
+use crate::record_inline_style;
 use crate::style::StyleComponentApplier;
+use crate::StyleLayers;
+use crate::StyledBy;
 use crate::UiComponentSpawner;
 use crate::UiComponentSpawnerActivator;
 use crate::UiId;
+use crate::UiIdLabel;
 
 use std::sync::Arc;
 
@@ -21,6 +27,8 @@ use super::UiBundleGeneratorStyler;
 
 use super::UiBundleGenerator;
 
+pub(crate) type UiComponentInserter = Box<dyn FnOnce(&mut EntityCommands<'_, '_, '_>)>;
+
 pub struct UiComponent<
     'w,
     's,
@@ -31,11 +39,13 @@ pub struct UiComponent<
     Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
 > {
     pub value: T,
+    pub(crate) styled: T,
     pub(crate) spawner: Option<&'a mut S>,
     pub(crate) phantom: PhantomData<&'w T>,
     pub(crate) phantom_2: PhantomData<&'s T>,
     pub(crate) styler: Arc<St>,
     pub(crate) id: Option<Id>,
+    pub(crate) inserters: Vec<UiComponentInserter>,
 }
 
 impl<
@@ -68,12 +78,14 @@ impl<
 {
     pub fn new(value: T, spawner: &'a mut S, styler: Arc<St>) -> Self {
         let result = Self {
+            styled: value.clone(),
             value,
             spawner: Some(spawner),
             phantom: PhantomData,
             phantom_2: PhantomData,
             styler,
             id: None,
+            inserters: Vec::new(),
         };
         result.style_with_styler()
     }
@@ -86,11 +98,13 @@ impl<
         let styler: Arc<StB> = Arc::new(styler);
         let result = UiComponent {
             value: self.value.clone(),
+            styled: self.value.clone(),
             spawner: self.spawner.take(),
             phantom: PhantomData,
             phantom_2: PhantomData,
             styler,
             id: self.id,
+            inserters: std::mem::take(&mut self.inserters),
         };
         result.style_with_styler()
     }
@@ -102,18 +116,55 @@ impl<
         let id = id;
         UiComponent {
             value: self.value.clone(),
+            styled: self.styled.clone(),
             spawner: self.spawner.take(),
             phantom: PhantomData,
             phantom_2: PhantomData,
             styler: self.styler.clone(),
             id: Some(id),
+            inserters: std::mem::take(&mut self.inserters),
         }
     }
 
+    pub fn insert<B: Bundle>(self, bundle: B) -> Self {
+        self.with_inserter(move |commands| {
+            commands.insert(bundle);
+        })
+    }
+
+    pub(crate) fn with_inserter(
+        mut self,
+        inserter: impl FnOnce(&mut EntityCommands) + 'static,
+    ) -> Self {
+        self.inserters.push(Box::new(inserter));
+        self
+    }
+
     pub(crate) fn style_with_styler(mut self) -> Self {
         self.value = self.value.clone().apply_styler(self.styler.as_ref());
+        self.styled = self.value.clone();
         self
     }
+
+    pub(crate) fn spawn_value(&mut self) -> Option<EntityCommands<'w, 's, 'a>> {
+        let id = self.id.take();
+        let inserters = std::mem::take(&mut self.inserters);
+        let spawner = self.spawner.take();
+        spawner.map(|spawner| {
+            let mut result = spawner.spawn_ui_component(&self.styled);
+            result.insert((StyledBy::new(self.styler.clone()), StyleLayers::default()));
+            result.add(capture_spawned_style);
+            self.value.spawn(&mut result);
+            if let Some(id) = id {
+                result.insert((UiId::new(id), UiIdLabel::new(&id)));
+            }
+            for inserter in inserters {
+                inserter(&mut result);
+            }
+            result.add(record_inline_style);
+            result
+        })
+    }
 }
 
 impl<
@@ -127,14 +178,7 @@ impl<
     > Drop for UiComponent<'w, 's, 'a, T, S, St, Id>
 {
     fn drop(&mut self) {
-        let id = self.id.take();
-        let spawner = self.spawner.take();
-        if let Some(spawner) = spawner {
-            let mut result = spawner.spawn_ui_component(&self.value);
-            if let Some(id) = id {
-                result.insert(UiId::new(id));
-            }
-        }
+        self.spawn_value();
     }
 }
 
@@ -165,15 +209,7 @@ impl<
     > UiComponentSpawnerActivator<'w, 's, 'a, T, S, St> for UiComponent<'w, 's, 'a, T, S, St, Id>
 {
     fn spawn(mut self) -> Option<EntityCommands<'w, 's, 'a>> {
-        let id = self.id.take();
-        let spawner = self.spawner.take();
-        spawner.map(|spawner| {
-            let mut result = spawner.spawn_ui_component(&self.value);
-            if let Some(id) = id {
-                result.insert(UiId::new(id));
-            }
-            result
-        })
+        self.spawn_value()
     }
 
     fn get_component_styler(&self) -> Arc<St> {
@@ -182,7 +218,7 @@ impl<
 
     fn apply_id(&self, commands: &mut EntityCommands) {
         if let Some(id) = self.id.as_ref() {
-            commands.insert(UiId::new(*id));
+            commands.insert((UiId::new(*id), UiIdLabel::new(id)));
         }
     }
 }
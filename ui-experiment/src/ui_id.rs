@@ -17,4 +17,17 @@ impl<T: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy> UiId<T> {
     }
 }
 
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct UiIdLabel(String);
+
+impl UiIdLabel {
+    pub fn new<T: Debug>(val: &T) -> Self {
+        Self(format!("{val:?}"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 pub type UIQuery<'w, 's, 'a, T, Q, M> = Query<'w, 's, (&'a UiId<T>, Q), With<M>>;
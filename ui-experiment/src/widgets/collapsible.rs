@@ -0,0 +1,221 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*};
+
+use crate::{
+    ExternalUiSpawner, Focusable, InternalUiSpawner, Layout, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiButtonBundle, UiComponent, UiNodeBundle,
+};
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Expanded(pub bool);
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct CollapsibleHeader;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct CollapsibleBody {
+    natural_height: Option<f32>,
+    progress: f32,
+    target: f32,
+    duration: f32,
+    overflow: Option<Overflow>,
+    max_height: Option<Val>,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CollapseAnimation {
+    pub duration: f32,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct AccordionGroup;
+
+pub trait CollapsibleSpawner<'w, 's, St: Styler>: ExternalUiSpawner<'w, 's, St> {
+    fn collapsible<'a>(
+        &'a mut self,
+        expanded: bool,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .flex_direction(FlexDirection::Column)
+            .insert(Expanded(expanded))
+    }
+
+    fn collapsible_header<'a>(
+        &'a mut self,
+    ) -> UiComponent<'w, 's, 'a, UiButtonBundle, Self::InternalSpawner, St, usize> {
+        self.button().insert((CollapsibleHeader, Focusable))
+    }
+
+    fn collapsible_body<'a>(
+        &'a mut self,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .flex_direction(FlexDirection::Column)
+            .insert(CollapsibleBody::default())
+    }
+
+    fn accordion<'a>(
+        &'a mut self,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .flex_direction(FlexDirection::Column)
+            .insert(AccordionGroup)
+    }
+}
+
+impl<'w, 's, St: Styler, E: ExternalUiSpawner<'w, 's, St>> CollapsibleSpawner<'w, 's, St> for E {}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn collapse_animation(self, duration: f32) -> Self {
+        self.insert(CollapseAnimation { duration })
+    }
+}
+
+fn toggle_on_press(
+    headers: Query<(Entity, &Interaction), (Changed<Interaction>, With<CollapsibleHeader>)>,
+    parents: Query<&Parent>,
+    mut sections: Query<&mut Expanded>,
+) {
+    for (entity, interaction) in headers.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(section) = parents
+            .iter_ancestors(entity)
+            .find(|ancestor| sections.contains(*ancestor))
+        else {
+            continue;
+        };
+        if let Ok(mut expanded) = sections.get_mut(section) {
+            expanded.0 = !expanded.0;
+        }
+    }
+}
+
+fn close_accordion_siblings(
+    mut sections: Query<(Entity, &mut Expanded)>,
+    groups: Query<(), With<AccordionGroup>>,
+    parents: Query<&Parent>,
+) {
+    let group_of = |entity: Entity| {
+        parents
+            .iter_ancestors(entity)
+            .find(|ancestor| groups.contains(*ancestor))
+    };
+
+    let opened: Vec<(Entity, Entity)> = sections
+        .iter_mut()
+        .filter(|(_, expanded)| expanded.is_changed() && expanded.0)
+        .filter_map(|(entity, _)| group_of(entity).map(|group| (group, entity)))
+        .collect();
+
+    for (group, opened) in opened {
+        if !sections.get(opened).is_ok_and(|(_, expanded)| expanded.0) {
+            continue;
+        }
+        for (entity, mut expanded) in sections.iter_mut() {
+            if entity != opened && expanded.0 && group_of(entity) == Some(group) {
+                expanded.0 = false;
+            }
+        }
+    }
+}
+
+fn apply_expanded(
+    sections: Query<(&Expanded, &Children, Option<&CollapseAnimation>), Changed<Expanded>>,
+    mut bodies: Query<&mut CollapsibleBody>,
+) {
+    for (expanded, children, animation) in sections.iter() {
+        let mut iter = bodies.iter_many_mut(children.iter());
+        while let Some(mut body) = iter.fetch_next() {
+            body.target = if expanded.0 { 1. } else { 0. };
+            body.duration = animation.map_or(0., |animation| animation.duration);
+        }
+    }
+}
+
+fn animate_collapsible_bodies(
+    time: Res<Time>,
+    mut bodies: Query<(&mut CollapsibleBody, &Node, &mut Style)>,
+) {
+    for (mut body, node, mut style) in bodies.iter_mut() {
+        if body.progress == body.target {
+            let display = if body.target > 0. {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if style.display != display {
+                style.display = display;
+            }
+            let height = node.size().y;
+            if display == Display::Flex && height > 0. && body.natural_height != Some(height) {
+                body.natural_height = Some(height);
+            }
+            continue;
+        }
+
+        let Some(natural_height) = body.natural_height.filter(|_| body.duration > 0.) else {
+            body.progress = body.target;
+            continue;
+        };
+
+        if body.overflow.is_none() {
+            body.overflow = Some(style.overflow);
+        }
+        if body.max_height.is_none() {
+            body.max_height = Some(style.max_height);
+        }
+        let step = time.delta_seconds() / body.duration;
+        body.progress = if body.target > body.progress {
+            (body.progress + step).min(body.target)
+        } else {
+            (body.progress - step).max(body.target)
+        };
+
+        style.display = Display::Flex;
+        style.overflow = Overflow::clip_y();
+        style.max_height = Val::Px(natural_height * body.progress);
+
+        if body.progress == body.target {
+            if let Some(max_height) = body.max_height.take() {
+                style.max_height = max_height;
+            }
+            if let Some(overflow) = body.overflow.take() {
+                style.overflow = overflow;
+            }
+            if body.target == 0. {
+                style.display = Display::None;
+            }
+        }
+    }
+}
+
+pub struct CollapsiblePlugin;
+
+impl Plugin for CollapsiblePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Expanded>().add_systems(
+            Update,
+            (
+                toggle_on_press,
+                close_accordion_siblings,
+                apply_expanded,
+                animate_collapsible_bodies,
+            )
+                .chain(),
+        );
+    }
+}
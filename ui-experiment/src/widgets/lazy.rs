@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{InternalUiSpawner, Styler, UiBundleGenerator, UiBundleGeneratorStyler, UiComponent};
+
+pub type LazyContent = Arc<dyn Fn(&mut ChildBuilder) + Send + Sync>;
+
+#[derive(Component, Clone)]
+pub struct LazyChildren(LazyContent);
+
+impl LazyChildren {
+    pub fn new(content: LazyContent) -> Self {
+        Self(content)
+    }
+
+    pub(crate) fn spawn(&self, commands: &mut Commands, entity: Entity) {
+        let content = self.0.clone();
+        commands
+            .entity(entity)
+            .remove::<LazyChildren>()
+            .with_children(move |builder| content(builder));
+    }
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler + Send + Sync + 'static,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn lazy_children<F: Fn((&mut ChildBuilder, Arc<St>)) + Send + Sync + 'static>(
+        self,
+        f: F,
+    ) -> Self {
+        let styler = self.styler.clone();
+        self.insert(LazyChildren(Arc::new(move |builder: &mut ChildBuilder| {
+            f((builder, styler.clone()))
+        })))
+    }
+}
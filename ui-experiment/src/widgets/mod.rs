@@ -0,0 +1,15 @@
+pub mod collapsible;
+pub mod lazy;
+pub mod modal;
+pub mod table;
+pub mod tabs;
+pub mod tooltip;
+pub mod tree_view;
+
+pub use collapsible::*;
+pub use lazy::*;
+pub use modal::*;
+pub use table::*;
+pub use tabs::*;
+pub use tooltip::*;
+pub use tree_view::*;
@@ -0,0 +1,208 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, utils::HashSet};
+
+use crate::{
+    BgColor, DespawnUiExt, ExternalUiSpawner, FocusPolicyApplier, FocusTrap, Focusable,
+    InternalUiSpawner, Layout, PendingDespawn, Styler, UiBundleGenerator, UiBundleGeneratorStyler,
+    UiComponent, UiFocus, UiNodeBundle, ZIndexApplier,
+};
+
+#[derive(Component)]
+pub struct Modal<T: Send + Sync + 'static> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> Default for Modal<T> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct ModalChoice<T: Clone + Send + Sync + 'static>(pub T);
+
+#[derive(Event, Clone, Debug)]
+pub struct ModalResult<T: Clone + Send + Sync + 'static> {
+    pub modal: Entity,
+    pub choice: Option<T>,
+}
+
+pub trait ModalSpawner<'w, 's, St: Styler>: ExternalUiSpawner<'w, 's, St> {
+    fn modal<'a, T: Send + Sync + 'static>(
+        &'a mut self,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .position_type(PositionType::Absolute)
+            .left(Val::Px(0.))
+            .top(Val::Px(0.))
+            .width(Val::Percent(100.))
+            .height(Val::Percent(100.))
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+            .bg(Color::rgba(0., 0., 0., 0.6))
+            .focus_policy(FocusPolicy::Block)
+            .insert((
+                ZIndex::Global(i32::MAX - 1),
+                Modal::<T>::default(),
+                FocusTrap,
+            ))
+    }
+}
+
+impl<'w, 's, St: Styler, E: ExternalUiSpawner<'w, 's, St>> ModalSpawner<'w, 's, St> for E {}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn modal_choice<C: Clone + Send + Sync + 'static>(self, choice: C) -> Self {
+        self.insert((ModalChoice(choice), Focusable, Interaction::default()))
+    }
+}
+
+#[derive(Resource)]
+struct ClosedModals<T: Send + Sync + 'static> {
+    modals: HashSet<Entity>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> Default for ClosedModals<T> {
+    fn default() -> Self {
+        Self {
+            modals: HashSet::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+fn clear_closed_modals<T: Clone + Send + Sync + 'static>(mut closed: ResMut<ClosedModals<T>>) {
+    closed.modals.clear();
+}
+
+fn close_modal<T: Clone + Send + Sync + 'static>(
+    commands: &mut Commands,
+    results: &mut EventWriter<ModalResult<T>>,
+    closed: &mut ClosedModals<T>,
+    modal: Entity,
+    choice: Option<T>,
+) {
+    if !closed.modals.insert(modal) {
+        return;
+    }
+    results.send(ModalResult { modal, choice });
+    commands.entity(modal).despawn_ui();
+}
+
+fn select_modal_choice<T: Clone + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut results: EventWriter<ModalResult<T>>,
+    mut closed: ResMut<ClosedModals<T>>,
+    choices: Query<(Entity, &Interaction, &ModalChoice<T>), Changed<Interaction>>,
+    modals: Query<(), (With<Modal<T>>, Without<PendingDespawn>)>,
+    parents: Query<&Parent>,
+) {
+    for (entity, interaction, choice) in choices.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(modal) = parents
+            .iter_ancestors(entity)
+            .find(|ancestor| modals.contains(*ancestor))
+        else {
+            continue;
+        };
+        close_modal(
+            &mut commands,
+            &mut results,
+            &mut closed,
+            modal,
+            Some(choice.0.clone()),
+        );
+    }
+}
+
+fn confirm_focused_choice<T: Clone + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut results: EventWriter<ModalResult<T>>,
+    mut closed: ResMut<ClosedModals<T>>,
+    input: Res<Input<KeyCode>>,
+    focus: Res<UiFocus>,
+    choices: Query<&ModalChoice<T>>,
+    modals: Query<(), (With<Modal<T>>, Without<PendingDespawn>)>,
+) {
+    if !input.any_just_pressed([KeyCode::Return, KeyCode::Space]) {
+        return;
+    }
+    let Some(modal) = focus.active_trap().filter(|trap| modals.contains(*trap)) else {
+        return;
+    };
+    let Some(choice) = focus
+        .focused()
+        .and_then(|focused| choices.get(focused).ok())
+    else {
+        return;
+    };
+    close_modal(
+        &mut commands,
+        &mut results,
+        &mut closed,
+        modal,
+        Some(choice.0.clone()),
+    );
+}
+
+fn close_modal_on_escape<T: Clone + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut results: EventWriter<ModalResult<T>>,
+    mut closed: ResMut<ClosedModals<T>>,
+    input: Res<Input<KeyCode>>,
+    focus: Res<UiFocus>,
+    modals: Query<(), (With<Modal<T>>, Without<PendingDespawn>)>,
+) {
+    if !input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    if let Some(modal) = focus.active_trap().filter(|trap| modals.contains(*trap)) {
+        close_modal(&mut commands, &mut results, &mut closed, modal, None);
+    }
+}
+
+pub struct ModalPlugin<T: Clone + Send + Sync + 'static> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Default for ModalPlugin<T> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Plugin for ModalPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ModalResult<T>>()
+            .init_resource::<ClosedModals<T>>()
+            .add_systems(
+                Update,
+                (
+                    clear_closed_modals::<T>,
+                    select_modal_choice::<T>,
+                    confirm_focused_choice::<T>,
+                    close_modal_on_escape::<T>,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,486 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use bevy::{
+    hierarchy::HierarchyQueryExt,
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    ui::RelativeCursorPosition,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    grid, ExternalUiSpawner, FocusPolicyApplier, Focusable, Layout, Styler, Theme, UiComponent,
+    UiComponentSpawnerActivator, UiNodeBundle,
+};
+
+const SELECTION_COLOR: Color = Color::rgba(0.3, 0.5, 0.9, 0.4);
+
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    pub title: String,
+    pub width: f32,
+}
+
+impl TableColumn {
+    pub fn new(title: impl Into<String>, width: f32) -> Self {
+        Self {
+            title: title.into(),
+            width,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ColumnResize {
+    column: usize,
+    start_cursor: f32,
+    start_width: f32,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Table {
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    order: Vec<usize>,
+    sort: Option<(usize, SortDirection)>,
+    first_row: usize,
+    visible_rows: usize,
+    selected: Option<usize>,
+    resizing: Option<ColumnResize>,
+}
+
+impl Table {
+    fn new(columns: Vec<TableColumn>, rows: Vec<Vec<String>>, visible_rows: usize) -> Self {
+        Self {
+            columns,
+            order: (0..rows.len()).collect(),
+            rows,
+            sort: None,
+            first_row: 0,
+            visible_rows,
+            selected: None,
+            resizing: None,
+        }
+    }
+
+    pub fn columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.selected = None;
+        self.apply_sort();
+    }
+
+    pub fn sort_by(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((current, SortDirection::Ascending)) if current == column => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        };
+        self.sort = Some((column, direction));
+        self.apply_sort();
+    }
+
+    pub fn scroll_to(&mut self, first_row: usize) {
+        self.first_row = first_row.min(self.rows.len().saturating_sub(self.visible_rows));
+    }
+
+    fn apply_sort(&mut self) {
+        self.order = (0..self.rows.len()).collect();
+        if let Some((column, direction)) = self.sort {
+            let rows = &self.rows;
+            self.order.sort_by(|a, b| {
+                let a = rows[*a].get(column).map(String::as_str).unwrap_or_default();
+                let b = rows[*b].get(column).map(String::as_str).unwrap_or_default();
+                let ordering = compare_cells(a, b);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        self.scroll_to(self.first_row);
+    }
+
+    fn row_at(&self, slot: usize) -> Option<usize> {
+        self.order.get(self.first_row + slot).copied()
+    }
+
+    fn header_label(&self, column: usize) -> String {
+        let title = self
+            .columns
+            .get(column)
+            .map(|column| column.title.as_str())
+            .unwrap_or_default();
+        match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => format!("{title} ▲"),
+            Some((sorted, SortDirection::Descending)) if sorted == column => format!("{title} ▼"),
+            _ => title.to_string(),
+        }
+    }
+
+    fn grid_template(&self) -> Vec<RepeatedGridTrack> {
+        grid_template(self.columns.iter().map(|column| column.width))
+    }
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+fn grid_template(widths: impl Iterator<Item = f32>) -> Vec<RepeatedGridTrack> {
+    widths.map(|width| grid::px(width).into()).collect()
+}
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct TableHeaderRow;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct TableBody;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableHeaderCell {
+    pub column: usize,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableHeaderLabel {
+    pub column: usize,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableResizeHandle {
+    pub column: usize,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableCell {
+    pub slot: usize,
+    pub column: usize,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TableRowSelected {
+    pub table: Entity,
+    pub row: usize,
+}
+
+const RESIZE_HANDLE_WIDTH: f32 = 6.;
+const MIN_COLUMN_WIDTH: f32 = 24.;
+const PIXELS_PER_ROW: f32 = 20.;
+
+fn spawn_table_contents<St: Styler>(
+    builder: &mut ChildBuilder,
+    styler: Arc<St>,
+    columns: &[TableColumn],
+    visible_rows: usize,
+) {
+    let template = grid_template(columns.iter().map(|column| column.width));
+    let mut p = (builder, styler);
+
+    p.node()
+        .display(Display::Grid)
+        .grid_template_columns(template.clone())
+        .insert(TableHeaderRow)
+        .with_children(|mut p| {
+            for (column, definition) in columns.iter().enumerate() {
+                p.node()
+                    .flex_direction(FlexDirection::Row)
+                    .insert((
+                        TableHeaderCell { column },
+                        Interaction::default(),
+                        Focusable,
+                    ))
+                    .with_children(|mut p| {
+                        p.text(definition.title.clone())
+                            .insert(TableHeaderLabel { column });
+                        p.node()
+                            .position_type(PositionType::Absolute)
+                            .right(Val::Px(0.))
+                            .top(Val::Px(0.))
+                            .width(Val::Px(RESIZE_HANDLE_WIDTH))
+                            .height(Val::Percent(100.))
+                            .focus_policy(FocusPolicy::Block)
+                            .insert((TableResizeHandle { column }, Interaction::default()));
+                    });
+            }
+        });
+
+    p.node()
+        .display(Display::Grid)
+        .overflow(Overflow::clip())
+        .grid_template_columns(template)
+        .insert((TableBody, RelativeCursorPosition::default()))
+        .with_children(|mut p| {
+            for slot in 0..visible_rows {
+                for column in 0..columns.len() {
+                    p.text("")
+                        .insert((TableCell { slot, column }, Interaction::default()));
+                }
+            }
+        });
+}
+
+pub trait TableSpawner<'w, 's, St: Styler + 'static>: ExternalUiSpawner<'w, 's, St> {
+    fn table<'a>(
+        &'a mut self,
+        columns: Vec<TableColumn>,
+        rows: Vec<Vec<String>>,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        let visible_rows = rows.len();
+        self.virtual_table(columns, rows, visible_rows)
+    }
+
+    fn virtual_table<'a>(
+        &'a mut self,
+        columns: Vec<TableColumn>,
+        rows: Vec<Vec<String>>,
+        visible_rows: usize,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        let styler = self.get_styler();
+        let table = Table::new(columns, rows, visible_rows);
+        let contents = table.columns.clone();
+        self.node()
+            .flex_direction(FlexDirection::Column)
+            .insert(table)
+            .with_inserter(move |commands| {
+                commands.with_children(|builder| {
+                    spawn_table_contents(builder, styler, &contents, visible_rows)
+                });
+            })
+    }
+}
+
+impl<'w, 's, St: Styler + 'static, E: ExternalUiSpawner<'w, 's, St>> TableSpawner<'w, 's, St>
+    for E
+{
+}
+
+fn table_of(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    tables: &Query<&mut Table>,
+) -> Option<Entity> {
+    parents
+        .iter_ancestors(entity)
+        .find(|ancestor| tables.contains(*ancestor))
+}
+
+fn sort_table_on_press(
+    headers: Query<(Entity, &Interaction, &TableHeaderCell), Changed<Interaction>>,
+    parents: Query<&Parent>,
+    mut tables: Query<&mut Table>,
+) {
+    for (entity, interaction, header) in headers.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(mut table) =
+            table_of(entity, &parents, &tables).and_then(|table| tables.get_mut(table).ok())
+        {
+            table.sort_by(header.column);
+        }
+    }
+}
+
+fn select_table_row(
+    cells: Query<(Entity, &Interaction, &TableCell), Changed<Interaction>>,
+    parents: Query<&Parent>,
+    mut tables: Query<&mut Table>,
+    mut events: EventWriter<TableRowSelected>,
+) {
+    for (entity, interaction, cell) in cells.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(table_entity) = table_of(entity, &parents, &tables) else {
+            continue;
+        };
+        let Ok(mut table) = tables.get_mut(table_entity) else {
+            continue;
+        };
+        let Some(row) = table.row_at(cell.slot) else {
+            continue;
+        };
+        if table.selected != Some(row) {
+            table.selected = Some(row);
+            events.send(TableRowSelected {
+                table: table_entity,
+                row,
+            });
+        }
+    }
+}
+
+fn resize_table_columns(
+    mouse: Res<Input<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    handles: Query<(Entity, &Interaction, &TableResizeHandle), Changed<Interaction>>,
+    parents: Query<&Parent>,
+    mut tables: Query<&mut Table>,
+) {
+    let Some(cursor) = windows
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .map(|cursor| cursor.x / ui_scale.scale as f32)
+    else {
+        return;
+    };
+
+    for (entity, interaction, handle) in handles.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(mut table) =
+            table_of(entity, &parents, &tables).and_then(|table| tables.get_mut(table).ok())
+        else {
+            continue;
+        };
+        let start_width = table.columns.get(handle.column).map(|column| column.width);
+        if let Some(start_width) = start_width {
+            table.resizing = Some(ColumnResize {
+                column: handle.column,
+                start_cursor: cursor,
+                start_width,
+            });
+        }
+    }
+
+    for mut table in tables.iter_mut() {
+        let Some(resize) = table.resizing else {
+            continue;
+        };
+        if !mouse.pressed(MouseButton::Left) {
+            table.resizing = None;
+            continue;
+        }
+        let width = (resize.start_width + cursor - resize.start_cursor).max(MIN_COLUMN_WIDTH);
+        if table.columns[resize.column].width != width {
+            table.columns[resize.column].width = width;
+        }
+    }
+}
+
+fn scroll_tables(
+    mut wheel: EventReader<MouseWheel>,
+    bodies: Query<(Entity, &RelativeCursorPosition), With<TableBody>>,
+    parents: Query<&Parent>,
+    mut tables: Query<&mut Table>,
+) {
+    let delta: f32 = wheel
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_ROW,
+        })
+        .sum();
+    let rows = delta.round() as isize;
+    if rows == 0 {
+        return;
+    }
+
+    for (entity, cursor) in bodies.iter() {
+        if !cursor.mouse_over() {
+            continue;
+        }
+        if let Some(mut table) =
+            table_of(entity, &parents, &tables).and_then(|table| tables.get_mut(table).ok())
+        {
+            let first_row = table.first_row.saturating_add_signed(-rows);
+            if first_row != table.first_row {
+                table.scroll_to(first_row);
+            }
+        }
+    }
+}
+
+fn update_tables(
+    theme: Option<Res<Theme>>,
+    tables: Query<(Entity, Ref<Table>)>,
+    children: Query<&Children>,
+    mut grids: Query<&mut Style, Or<(With<TableHeaderRow>, With<TableBody>)>>,
+    mut labels: Query<(&TableHeaderLabel, &mut Text), Without<TableCell>>,
+    mut cells: Query<(&TableCell, &mut Text, &mut BackgroundColor)>,
+) {
+    let retheme = theme.as_ref().is_some_and(|theme| theme.is_changed());
+    let selection = theme
+        .and_then(|theme| theme.colors.get("selection").copied())
+        .unwrap_or(SELECTION_COLOR);
+    for (entity, table) in tables.iter() {
+        if !retheme && !table.is_changed() {
+            continue;
+        }
+        let template = table.grid_template();
+        for descendant in children.iter_descendants(entity) {
+            if let Ok(mut style) = grids.get_mut(descendant) {
+                if style.grid_template_columns != template {
+                    style.grid_template_columns = template.clone();
+                }
+            } else if let Ok((label, mut text)) = labels.get_mut(descendant) {
+                if let Some(section) = text.sections.first_mut() {
+                    section.value = table.header_label(label.column);
+                }
+            } else if let Ok((cell, mut text, mut background)) = cells.get_mut(descendant) {
+                let row = table.row_at(cell.slot);
+                if let Some(section) = text.sections.first_mut() {
+                    section.value = row
+                        .and_then(|row| table.rows[row].get(cell.column))
+                        .cloned()
+                        .unwrap_or_default();
+                }
+                background.0 = if row.is_some() && row == table.selected {
+                    selection
+                } else {
+                    Color::NONE
+                };
+            }
+        }
+    }
+}
+
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TableRowSelected>().add_systems(
+            Update,
+            (
+                (
+                    sort_table_on_press,
+                    select_table_row,
+                    resize_table_columns,
+                    scroll_tables,
+                ),
+                update_tables,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, utils::HashMap};
+
+use crate::{
+    Disabled, ExternalUiSpawner, Focusable, InheritedDisabled, InternalUiSpawner, Layout,
+    LazyChildren, Styler, UiBundleGenerator, UiBundleGeneratorStyler, UiButtonBundle, UiComponent,
+    UiNodeBundle, VisibilityApplier,
+};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ActiveTab<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    active: Id,
+    applied: Option<Id>,
+}
+
+impl<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> ActiveTab<Id> {
+    pub fn new(active: Id) -> Self {
+        Self {
+            active,
+            applied: None,
+        }
+    }
+
+    pub fn active(&self) -> Id {
+        self.active
+    }
+
+    pub fn set(&mut self, active: Id) {
+        self.active = active;
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabHeader<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    pub Id,
+);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabPanel<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    pub Id,
+);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabShortcut(pub KeyCode);
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TabChanged<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    pub tabs: Entity,
+    pub previous: Id,
+    pub active: Id,
+}
+
+pub trait TabsSpawner<'w, 's, St: Styler>: ExternalUiSpawner<'w, 's, St> {
+    fn tabs<'a, Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+        &'a mut self,
+        active: Id,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .flex_direction(FlexDirection::Column)
+            .insert(ActiveTab::new(active))
+    }
+
+    fn tab_header<'a, Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+        &'a mut self,
+        tab: Id,
+    ) -> UiComponent<'w, 's, 'a, UiButtonBundle, Self::InternalSpawner, St, usize> {
+        self.button().insert((TabHeader(tab), Focusable))
+    }
+
+    fn tab_panel<'a, Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+        &'a mut self,
+        tab: Id,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        self.node()
+            .display(Display::None)
+            .insert((Visibility::Hidden, TabPanel(tab)))
+    }
+}
+
+impl<'w, 's, St: Styler, E: ExternalUiSpawner<'w, 's, St>> TabsSpawner<'w, 's, St> for E {}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn tab_shortcut(self, key: KeyCode) -> Self {
+        self.insert(TabShortcut(key))
+    }
+}
+
+fn select_tab<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    header: Entity,
+    tab: Id,
+    parents: &Query<&Parent>,
+    tabs: &mut Query<&mut ActiveTab<Id>>,
+) {
+    let Some(container) = parents
+        .iter_ancestors(header)
+        .find(|ancestor| tabs.contains(*ancestor))
+    else {
+        return;
+    };
+    if let Ok(mut active) = tabs.get_mut(container) {
+        if active.active != tab {
+            active.set(tab);
+        }
+    }
+}
+
+fn select_tab_on_press<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    headers: Query<(Entity, &Interaction, &TabHeader<Id>), Changed<Interaction>>,
+    parents: Query<&Parent>,
+    mut tabs: Query<&mut ActiveTab<Id>>,
+) {
+    for (entity, interaction, header) in headers.iter() {
+        if *interaction == Interaction::Pressed {
+            select_tab(entity, header.0, &parents, &mut tabs);
+        }
+    }
+}
+
+fn select_tab_on_shortcut<
+    Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+>(
+    input: Res<Input<KeyCode>>,
+    headers: Query<
+        (Entity, &TabShortcut, &TabHeader<Id>),
+        (Without<Disabled>, Without<InheritedDisabled>),
+    >,
+    parents: Query<&Parent>,
+    mut tabs: Query<&mut ActiveTab<Id>>,
+) {
+    for (entity, shortcut, header) in headers.iter() {
+        if input.just_pressed(shortcut.0) {
+            select_tab(entity, header.0, &parents, &mut tabs);
+        }
+    }
+}
+
+fn apply_active_tab<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    mut commands: Commands,
+    mut changed_events: EventWriter<TabChanged<Id>>,
+    mut changed: Query<(Entity, &mut ActiveTab<Id>), Changed<ActiveTab<Id>>>,
+    containers: Query<(), With<ActiveTab<Id>>>,
+    mut panels: Query<(
+        Entity,
+        &TabPanel<Id>,
+        &mut Style,
+        &mut Visibility,
+        Option<&LazyChildren>,
+    )>,
+    parents: Query<&Parent>,
+) {
+    let mut updated = HashMap::new();
+    for (entity, mut active) in changed.iter_mut() {
+        if active.applied == Some(active.active) {
+            continue;
+        }
+        if let Some(previous) = active.applied {
+            changed_events.send(TabChanged {
+                tabs: entity,
+                previous,
+                active: active.active,
+            });
+        }
+        let current = active.active;
+        active.bypass_change_detection().applied = Some(current);
+        updated.insert(entity, current);
+    }
+
+    if updated.is_empty() {
+        return;
+    }
+
+    for (entity, panel, mut style, mut visibility, lazy) in panels.iter_mut() {
+        let Some(active) = parents
+            .iter_ancestors(entity)
+            .find(|ancestor| containers.contains(*ancestor))
+            .and_then(|container| updated.get(&container))
+        else {
+            continue;
+        };
+
+        if panel.0 == *active {
+            style.display = Display::Flex;
+            *visibility = Visibility::Inherited;
+            if let Some(lazy) = lazy {
+                lazy.spawn(&mut commands, entity);
+            }
+        } else {
+            style.display = Display::None;
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+pub struct TabsPlugin<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    phantom: PhantomData<fn() -> Id>,
+}
+
+impl<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> Default
+    for TabsPlugin<Id>
+{
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> Plugin
+    for TabsPlugin<Id>
+{
+    fn build(&self, app: &mut App) {
+        app.add_event::<TabChanged<Id>>().add_systems(
+            Update,
+            (
+                (select_tab_on_press::<Id>, select_tab_on_shortcut::<Id>),
+                apply_active_tab::<Id>,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    DespawnUiExt, ExternalUiSpawner, InternalUiSpawner, Layout, Styler, UiBundleGenerator,
+    UiBundleGeneratorStyler, UiComponent, UiComponentSpawnerActivator, VisibilityApplier,
+    ZIndexApplier,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TooltipAnchor {
+    #[default]
+    Cursor,
+    Target,
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct TooltipSettings {
+    pub delay: f32,
+    pub offset: Vec2,
+    pub anchor: TooltipAnchor,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            delay: 0.5,
+            offset: Vec2::new(12., 12.),
+            anchor: TooltipAnchor::Cursor,
+        }
+    }
+}
+
+pub type TooltipContent = Arc<dyn Fn(&mut ChildBuilder) + Send + Sync>;
+
+#[derive(Component, Clone)]
+pub struct Tooltip {
+    content: TooltipContent,
+}
+
+#[derive(Component, Default)]
+pub struct TooltipState {
+    hovered_for: f32,
+    tooltip: Option<Entity>,
+}
+
+impl TooltipState {
+    pub fn tooltip(&self) -> Option<Entity> {
+        self.tooltip
+    }
+}
+
+#[derive(Component)]
+pub struct TooltipNode {
+    pub target: Entity,
+}
+
+impl<
+        'w,
+        's,
+        'a,
+        T: UiBundleGenerator + UiBundleGeneratorStyler,
+        S: InternalUiSpawner<'w, 's>,
+        St: Styler,
+        Id: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+    > UiComponent<'w, 's, 'a, T, S, St, Id>
+{
+    pub fn tooltip(self, text: impl Into<String>) -> Self {
+        let text: String = text.into();
+        self.tooltip_with(move |mut p| {
+            p.text(text.clone());
+        })
+    }
+
+    pub fn tooltip_with<F: Fn((&mut ChildBuilder, Arc<St>)) + Send + Sync + 'static>(
+        self,
+        f: F,
+    ) -> Self {
+        let styler = self.styler.clone();
+        self.insert((
+            Tooltip {
+                content: Arc::new(move |builder: &mut ChildBuilder| f((builder, styler.clone()))),
+            },
+            TooltipState::default(),
+            Interaction::default(),
+        ))
+    }
+}
+
+fn update_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<TooltipSettings>,
+    mut targets: Query<(Entity, &Tooltip, &Interaction, &mut TooltipState)>,
+) {
+    for (entity, tooltip, interaction, mut state) in targets.iter_mut() {
+        if *interaction == Interaction::None {
+            state.hovered_for = 0.;
+            if let Some(node) = state.tooltip.take() {
+                commands.entity(node).despawn_ui();
+            }
+            continue;
+        }
+
+        state.hovered_for += time.delta_seconds();
+        if state.tooltip.is_some() || state.hovered_for < settings.delay {
+            continue;
+        }
+
+        let content = tooltip.content.clone();
+        state.tooltip = commands
+            .node()
+            .position_type(PositionType::Absolute)
+            .insert((
+                ZIndex::Global(i32::MAX),
+                Visibility::Hidden,
+                TooltipNode { target: entity },
+            ))
+            .with_children(move |(builder, _)| content(builder))
+            .map(|node| node.id());
+    }
+}
+
+fn place_tooltip(
+    anchor: TooltipAnchor,
+    anchor_min: Vec2,
+    anchor_max: Vec2,
+    size: Vec2,
+    offset: Vec2,
+    bounds: Vec2,
+) -> Vec2 {
+    let mut position = match anchor {
+        TooltipAnchor::Cursor => anchor_max + offset,
+        TooltipAnchor::Target => Vec2::new(anchor_min.x, anchor_max.y + offset.y),
+    };
+
+    if position.x + size.x > bounds.x {
+        position.x = match anchor {
+            TooltipAnchor::Cursor => anchor_min.x - offset.x - size.x,
+            TooltipAnchor::Target => anchor_max.x - size.x,
+        };
+    }
+    if position.y + size.y > bounds.y {
+        position.y = anchor_min.y - offset.y - size.y;
+    }
+
+    position.max(Vec2::ZERO)
+}
+
+fn position_tooltips(
+    mut commands: Commands,
+    settings: Res<TooltipSettings>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    targets: Query<(&Node, &GlobalTransform), With<Tooltip>>,
+    mut tooltips: Query<(Entity, &TooltipNode, &Node, &mut Style, &mut Visibility)>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let scale = ui_scale.scale as f32;
+    let bounds = Vec2::new(window.width(), window.height()) / scale;
+    let cursor = window.cursor_position().map(|p| p / scale);
+
+    for (entity, tooltip, node, mut style, mut visibility) in tooltips.iter_mut() {
+        let Ok((target_node, target_transform)) = targets.get(tooltip.target) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let size = node.size();
+        if size == Vec2::ZERO {
+            continue;
+        }
+
+        let target_min = target_transform.translation().truncate() - target_node.size() / 2.;
+        let target_max = target_min + target_node.size();
+        let (anchor, anchor_min, anchor_max) = match (settings.anchor, cursor) {
+            (TooltipAnchor::Cursor, Some(cursor)) => (TooltipAnchor::Cursor, cursor, cursor),
+            _ => (TooltipAnchor::Target, target_min, target_max),
+        };
+
+        let position = place_tooltip(
+            anchor,
+            anchor_min,
+            anchor_max,
+            size,
+            settings.offset,
+            bounds,
+        );
+        style.left = Val::Px(position.x);
+        style.top = Val::Px(position.y);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipSettings>()
+            .add_systems(Update, (update_tooltips, position_tooltips).chain());
+    }
+}
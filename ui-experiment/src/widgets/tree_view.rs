@@ -0,0 +1,455 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, ui::UiStack, utils::HashMap};
+
+use crate::{
+    CollapsibleBody, CollapsibleHeader, CollapsibleSpawner, Expanded, ExternalUiSpawner, Focusable,
+    Layout, Styler, UiComponent, UiComponentSpawnerActivator, UiFocus, UiNodeBundle,
+};
+
+pub trait TreeSource<K>: Send + Sync + 'static {
+    fn roots(&self, world: &World) -> Vec<K>;
+    fn children(&self, world: &World, key: K) -> Vec<K>;
+    fn label(&self, world: &World, key: K) -> String;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EntityTreeSource {
+    pub root: Entity,
+}
+
+impl TreeSource<Entity> for EntityTreeSource {
+    fn roots(&self, world: &World) -> Vec<Entity> {
+        self.children(world, self.root)
+    }
+
+    fn children(&self, world: &World, key: Entity) -> Vec<Entity> {
+        world
+            .get::<Children>(key)
+            .map(|children| children.to_vec())
+            .unwrap_or_default()
+    }
+
+    fn label(&self, world: &World, key: Entity) -> String {
+        world
+            .get::<Name>(key)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("{key:?}"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StaticTree<K: Eq + Hash> {
+    roots: Vec<K>,
+    children: HashMap<K, Vec<K>>,
+    labels: HashMap<K, String>,
+}
+
+impl<K: Eq + Hash> Default for StaticTree<K> {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            children: HashMap::new(),
+            labels: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> StaticTree<K> {
+    pub fn with_node(mut self, parent: Option<K>, key: K, label: impl Into<String>) -> Self {
+        match parent {
+            Some(parent) => self.children.entry(parent).or_default().push(key.clone()),
+            None => self.roots.push(key.clone()),
+        }
+        self.labels.insert(key, label.into());
+        self
+    }
+}
+
+impl<K: Debug + Eq + Hash + Send + Sync + Clone + Copy + 'static> TreeSource<K> for StaticTree<K> {
+    fn roots(&self, _: &World) -> Vec<K> {
+        self.roots.clone()
+    }
+
+    fn children(&self, _: &World, key: K) -> Vec<K> {
+        self.children.get(&key).cloned().unwrap_or_default()
+    }
+
+    fn label(&self, _: &World, key: K) -> String {
+        self.labels
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| format!("{key:?}"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeRowInfo<K> {
+    pub tree: Entity,
+    pub key: K,
+    pub label: String,
+    pub depth: usize,
+    pub has_children: bool,
+}
+
+pub type TreeRowSpawner<K> = Arc<dyn Fn(&mut ChildBuilder, TreeRowInfo<K>) + Send + Sync>;
+
+#[derive(Component)]
+pub struct TreeView<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    source: Arc<dyn TreeSource<K>>,
+    spawn_row: TreeRowSpawner<K>,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TreeSelection<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    Option<K>,
+);
+
+impl<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> TreeSelection<K> {
+    pub fn selected(&self) -> Option<K> {
+        self.0
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TreeRow<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    pub tree: Entity,
+    pub key: K,
+    pub depth: usize,
+    pub has_children: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct LazyTreeRow;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct TreeArrow;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct TreeLabel;
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TreeSelectionChanged<
+    K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+> {
+    pub tree: Entity,
+    pub selected: K,
+}
+
+const TREE_INDENT: f32 = 16.;
+
+fn arrow(has_children: bool, expanded: bool) -> &'static str {
+    match (has_children, expanded) {
+        (false, _) => " ",
+        (true, false) => "▶",
+        (true, true) => "▼",
+    }
+}
+
+fn spawn_tree_row<
+    St: Styler,
+    K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+>(
+    builder: &mut ChildBuilder,
+    styler: Arc<St>,
+    row: TreeRowInfo<K>,
+) {
+    let mut p = (builder, styler);
+    p.node()
+        .flex_direction(FlexDirection::Column)
+        .insert((
+            TreeRow {
+                tree: row.tree,
+                key: row.key,
+                depth: row.depth,
+                has_children: row.has_children,
+            },
+            Expanded(false),
+            LazyTreeRow,
+        ))
+        .with_children(|mut p| {
+            p.node()
+                .flex_direction(FlexDirection::Row)
+                .align_items(AlignItems::Center)
+                .padding(UiRect::left(Val::Px(TREE_INDENT * row.depth as f32)))
+                .with_children(|mut p| {
+                    p.button()
+                        .insert((CollapsibleHeader, TreeArrow))
+                        .with_children(|mut p| {
+                            p.text(arrow(row.has_children, false));
+                        });
+                    p.button()
+                        .insert((TreeLabel, Focusable))
+                        .with_children(|mut p| {
+                            p.text(row.label.clone());
+                        });
+                });
+            p.collapsible_body();
+        });
+}
+
+pub trait TreeViewSpawner<'w, 's, St: Styler>: ExternalUiSpawner<'w, 's, St> {
+    fn tree_view<'a, K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+        &'a mut self,
+        source: impl TreeSource<K>,
+    ) -> UiComponent<'w, 's, 'a, UiNodeBundle, Self::InternalSpawner, St, usize> {
+        let styler = self.get_styler();
+        self.node().flex_direction(FlexDirection::Column).insert((
+            TreeView {
+                source: Arc::new(source),
+                spawn_row: Arc::new(move |builder: &mut ChildBuilder, row: TreeRowInfo<K>| {
+                    spawn_tree_row(builder, styler.clone(), row)
+                }),
+            },
+            TreeSelection::<K>(None),
+        ))
+    }
+}
+
+impl<'w, 's, St: Styler, E: ExternalUiSpawner<'w, 's, St>> TreeViewSpawner<'w, 's, St> for E {}
+
+fn row_infos<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    world: &World,
+    tree_entity: Entity,
+    tree: &TreeView<K>,
+    keys: Vec<K>,
+    depth: usize,
+) -> Vec<TreeRowInfo<K>> {
+    keys.into_iter()
+        .map(|key| TreeRowInfo {
+            tree: tree_entity,
+            key,
+            label: tree.source.label(world, key),
+            depth,
+            has_children: !tree.source.children(world, key).is_empty(),
+        })
+        .collect()
+}
+
+fn spawn_rows<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    commands: &mut Commands,
+    parent: Entity,
+    tree: &TreeView<K>,
+    rows: Vec<TreeRowInfo<K>>,
+) {
+    let spawn_row = tree.spawn_row.clone();
+    commands.entity(parent).with_children(move |builder| {
+        for row in rows {
+            spawn_row(builder, row);
+        }
+    });
+}
+
+fn spawn_tree_rows<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    world: &World,
+    mut commands: Commands,
+    new_trees: Query<(Entity, &TreeView<K>), Added<TreeView<K>>>,
+    trees: Query<&TreeView<K>>,
+    expanded_rows: Query<(Entity, &TreeRow<K>, &Expanded, &Children), With<LazyTreeRow>>,
+    bodies: Query<(), With<CollapsibleBody>>,
+) {
+    for (entity, tree) in new_trees.iter() {
+        let rows = row_infos(world, entity, tree, tree.source.roots(world), 0);
+        spawn_rows(&mut commands, entity, tree, rows);
+    }
+
+    for (entity, row, expanded, children) in expanded_rows.iter() {
+        if !expanded.0 {
+            continue;
+        }
+        let Ok(tree) = trees.get(row.tree) else {
+            continue;
+        };
+        commands.entity(entity).remove::<LazyTreeRow>();
+        let Some(body) = children
+            .iter()
+            .copied()
+            .find(|child| bodies.contains(*child))
+        else {
+            continue;
+        };
+        let keys = tree.source.children(world, row.key);
+        let rows = row_infos(world, row.tree, tree, keys, row.depth + 1);
+        spawn_rows(&mut commands, body, tree, rows);
+    }
+}
+
+fn update_tree_arrows<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    rows: Query<(Entity, &TreeRow<K>, &Expanded), Changed<Expanded>>,
+    arrows: Query<&Children, With<TreeArrow>>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, row, expanded) in rows.iter() {
+        let Some(arrow_entity) = children
+            .iter_descendants(entity)
+            .find(|descendant| arrows.contains(*descendant))
+        else {
+            continue;
+        };
+        let Ok(arrow_children) = arrows.get(arrow_entity) else {
+            continue;
+        };
+        let mut iter = texts.iter_many_mut(arrow_children.iter());
+        while let Some(mut text) = iter.fetch_next() {
+            if let Some(section) = text.sections.first_mut() {
+                section.value = arrow(row.has_children, expanded.0).to_string();
+            }
+        }
+    }
+}
+
+fn focused_row<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    label: Entity,
+    parents: &Query<&Parent>,
+    rows: &Query<(&TreeRow<K>, &mut Expanded)>,
+) -> Option<Entity> {
+    parents
+        .iter_ancestors(label)
+        .find(|ancestor| rows.contains(*ancestor))
+}
+
+fn select_focused_tree_rows<
+    K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static,
+>(
+    focus: Res<UiFocus>,
+    labels: Query<(), With<TreeLabel>>,
+    parents: Query<&Parent>,
+    rows: Query<&TreeRow<K>>,
+    mut selections: Query<&mut TreeSelection<K>>,
+    mut events: EventWriter<TreeSelectionChanged<K>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    let Some(label) = focus.focused().filter(|focused| labels.contains(*focused)) else {
+        return;
+    };
+    let Some(row) = parents
+        .iter_ancestors(label)
+        .find_map(|ancestor| rows.get(ancestor).ok())
+    else {
+        return;
+    };
+    let Ok(mut selection) = selections.get_mut(row.tree) else {
+        return;
+    };
+    if selection.0 != Some(row.key) {
+        selection.0 = Some(row.key);
+        events.send(TreeSelectionChanged {
+            tree: row.tree,
+            selected: row.key,
+        });
+    }
+}
+
+fn navigate_tree<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static>(
+    input: Res<Input<KeyCode>>,
+    mut focus: ResMut<UiFocus>,
+    ui_stack: Res<UiStack>,
+    labels: Query<(), With<TreeLabel>>,
+    parents: Query<&Parent>,
+    mut rows: Query<(&TreeRow<K>, &mut Expanded)>,
+) {
+    let Some(label) = focus.focused().filter(|focused| labels.contains(*focused)) else {
+        return;
+    };
+    let Some(row_entity) = focused_row(label, &parents, &rows) else {
+        return;
+    };
+    let Ok((row, _)) = rows.get(row_entity) else {
+        return;
+    };
+    let tree = row.tree;
+
+    let label_row = |label: Entity| focused_row(label, &parents, &rows);
+    let is_visible = |label: Entity| {
+        parents
+            .iter_ancestors(label)
+            .filter_map(|ancestor| rows.get(ancestor).ok())
+            .skip(1)
+            .all(|(_, expanded)| expanded.0)
+    };
+    let visible: Vec<Entity> = ui_stack
+        .uinodes
+        .iter()
+        .copied()
+        .filter(|entity| labels.contains(*entity))
+        .filter(|entity| {
+            label_row(*entity)
+                .and_then(|row| rows.get(row).ok())
+                .is_some_and(|(row, _)| row.tree == tree)
+        })
+        .filter(|entity| is_visible(*entity))
+        .collect();
+    let Some(position) = visible.iter().position(|entity| *entity == label) else {
+        return;
+    };
+
+    if input.just_pressed(KeyCode::Down) {
+        if let Some(next) = visible.get(position + 1) {
+            focus.focus(*next);
+        }
+    } else if input.just_pressed(KeyCode::Up) {
+        if let Some(previous) = position.checked_sub(1).and_then(|p| visible.get(p)) {
+            focus.focus(*previous);
+        }
+    } else if input.just_pressed(KeyCode::Right) {
+        if let Ok((row, mut expanded)) = rows.get_mut(row_entity) {
+            if row.has_children && !expanded.0 {
+                expanded.0 = true;
+            }
+        }
+    } else if input.just_pressed(KeyCode::Left) {
+        let parent_row = parents
+            .iter_ancestors(row_entity)
+            .find(|ancestor| rows.contains(*ancestor));
+        if let Ok((_, mut expanded)) = rows.get_mut(row_entity) {
+            if expanded.0 {
+                expanded.0 = false;
+                return;
+            }
+        }
+        if let Some(parent_label) = parent_row.and_then(|parent_row| {
+            visible
+                .iter()
+                .copied()
+                .find(|label| focused_row(*label, &parents, &rows) == Some(parent_row))
+        }) {
+            focus.focus(parent_label);
+        }
+    }
+}
+
+pub struct TreeViewPlugin<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> {
+    phantom: PhantomData<fn() -> K>,
+}
+
+impl<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> Default
+    for TreeViewPlugin<K>
+{
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Debug + PartialEq + Eq + Hash + Sync + Send + Clone + Copy + 'static> Plugin
+    for TreeViewPlugin<K>
+{
+    fn build(&self, app: &mut App) {
+        app.add_event::<TreeSelectionChanged<K>>().add_systems(
+            Update,
+            (
+                spawn_tree_rows::<K>,
+                update_tree_arrows::<K>,
+                select_focused_tree_rows::<K>,
+                navigate_tree::<K>,
+            ),
+        );
+    }
+}